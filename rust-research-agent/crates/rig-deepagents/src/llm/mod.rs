@@ -0,0 +1,160 @@
+//! LLM provider abstraction
+//!
+//! `LLMProvider` is the trait agent vertices call to get completions. A
+//! provider implements either `complete` (buffered) or `complete_stream`
+//! (incremental) — each has a default built atop the other, so existing
+//! non-streaming providers keep working unmodified while new providers can
+//! opt into streaming.
+
+pub mod rate_limit;
+
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+
+use crate::error::DeepAgentError;
+use crate::middleware::ToolDefinition;
+use crate::state::Message;
+use crate::workflow::vertices::streaming::StreamAssembler;
+
+pub use rate_limit::{RateLimitConfig, RateLimitedProvider};
+
+/// Per-call tuning knobs passed to a provider
+#[derive(Debug, Clone, Default)]
+pub struct LLMConfig {
+    pub model: String,
+    pub temperature: Option<f64>,
+    /// Rate-limit/retry behavior to apply to this provider. Set via
+    /// [`Self::with_rate_limit`] and passed to
+    /// [`RateLimitedProvider::from_llm_config`] to opt a provider into
+    /// throttling without constructing a `RateLimitedProvider` by hand.
+    pub rate_limit: Option<RateLimitConfig>,
+}
+
+impl LLMConfig {
+    /// Start a config targeting `model` (pass `""` to use the provider's default)
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            temperature: None,
+            rate_limit: None,
+        }
+    }
+
+    /// Set the sampling temperature
+    pub fn with_temperature(mut self, temperature: f64) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Opt this config into rate-limiting; see [`RateLimitedProvider::from_llm_config`]
+    pub fn with_rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+}
+
+/// A completed assistant turn
+#[derive(Debug, Clone)]
+pub struct LLMResponse {
+    pub message: Message,
+}
+
+impl LLMResponse {
+    pub fn new(message: Message) -> Self {
+        Self { message }
+    }
+}
+
+/// A provider of LLM completions, buffered or streamed
+#[async_trait]
+pub trait LLMProvider: Send + Sync {
+    /// Produce a complete assistant turn in one shot
+    ///
+    /// Default implementation buffers `complete_stream` into a single
+    /// response; providers that only support non-streaming APIs should
+    /// override this instead of `complete_stream`.
+    async fn complete(
+        &self,
+        messages: &[Message],
+        tools: &[ToolDefinition],
+        config: Option<&LLMConfig>,
+    ) -> Result<LLMResponse, DeepAgentError> {
+        let mut deltas = self.complete_stream(messages, tools, config).await?;
+        let mut assembler = StreamAssembler::new();
+        while let Some(delta) = deltas.next().await {
+            assembler.push(&delta);
+        }
+        let (content, tool_calls) = assembler
+            .finish()
+            .map_err(|e| DeepAgentError::AgentExecution(format!("malformed tool-call stream: {e}")))?;
+
+        Ok(LLMResponse::new(Message {
+            role: crate::state::Role::Assistant,
+            content,
+            tool_calls: if tool_calls.is_empty() {
+                None
+            } else {
+                Some(tool_calls)
+            },
+            tool_call_id: None,
+        }))
+    }
+
+    /// Produce an assistant turn as a stream of incremental deltas
+    ///
+    /// Default implementation wraps `complete` as a single terminal delta;
+    /// providers that support native streaming should override this instead.
+    async fn complete_stream(
+        &self,
+        messages: &[Message],
+        tools: &[ToolDefinition],
+        config: Option<&LLMConfig>,
+    ) -> Result<BoxStream<'_, crate::workflow::vertices::streaming::StreamDelta>, DeepAgentError>
+    {
+        let response = self.complete(messages, tools, config).await?;
+        let content_delta = crate::workflow::vertices::streaming::StreamDelta {
+            content: Some(response.message.content),
+            tool_call: None,
+            finished: false,
+        };
+        // Tool calls from a buffered response arrive pre-assembled rather
+        // than fragmented, so surface each as its own delta; index position
+        // is correct for the common case of one-shot tool-call lists.
+        let tool_deltas: Vec<_> = response
+            .message
+            .tool_calls
+            .unwrap_or_default()
+            .into_iter()
+            .enumerate()
+            .map(|(index, tc)| crate::workflow::vertices::streaming::StreamDelta {
+                content: None,
+                tool_call: Some(crate::workflow::vertices::streaming::ToolCallDelta {
+                    index,
+                    id: Some(tc.id),
+                    name: Some(tc.name),
+                    arguments_fragment: Some(tc.arguments.to_string()),
+                }),
+                finished: false,
+            })
+            .collect();
+        let final_delta = crate::workflow::vertices::streaming::StreamDelta {
+            content: None,
+            tool_call: None,
+            finished: true,
+        };
+
+        Ok(stream::iter(
+            std::iter::once(content_delta)
+                .chain(tool_deltas)
+                .chain(std::iter::once(final_delta))
+                .collect::<Vec<_>>(),
+        )
+        .boxed())
+    }
+
+    /// A human-readable name for this provider, for logging/metrics
+    fn name(&self) -> &str;
+
+    /// The model used when no explicit model is set in an `LLMConfig`
+    fn default_model(&self) -> &str;
+}
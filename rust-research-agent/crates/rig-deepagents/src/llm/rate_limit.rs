@@ -0,0 +1,246 @@
+//! A rate-limiting, retrying decorator over any `LLMProvider`
+//!
+//! Agent vertices call `self.llm.complete(...)` with no throttling of their
+//! own, so several concurrent agents sharing a provider can easily trip its
+//! rate limit. `RateLimitedProvider` wraps an inner provider with a
+//! token-bucket limiter (requests/minute) plus a cap on in-flight calls, and
+//! retries rate-limit errors with the shared `RetryPolicy` backoff.
+
+use async_trait::async_trait;
+use futures::stream::{BoxStream, Stream};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+use tokio::time::{Duration, Instant};
+
+use crate::error::DeepAgentError;
+use crate::llm::{LLMConfig, LLMProvider, LLMResponse};
+use crate::middleware::ToolDefinition;
+use crate::pregel::config::RetryPolicy;
+use crate::state::Message;
+use crate::workflow::vertices::streaming::StreamDelta;
+
+/// How a `RateLimitedProvider` should be throttled
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// Sustained request rate, in requests per minute. The bucket starts
+    /// full and holds this many tokens, so up to this many requests may also
+    /// be issued at once as a burst.
+    pub requests_per_minute: u32,
+    /// Maximum number of calls in flight at once, across all callers sharing
+    /// the wrapped provider
+    pub max_concurrent: usize,
+    /// Backoff applied when the inner provider reports a rate-limit error
+    pub retry_policy: RetryPolicy,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_minute: 60,
+            max_concurrent: 4,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+}
+
+/// A simple token bucket refilled at a constant rate
+struct TokenBucket {
+    capacity: f64,
+    tokens: Mutex<f64>,
+    refill_per_sec: f64,
+    last_refill: Mutex<Instant>,
+}
+
+impl TokenBucket {
+    fn new(requests_per_minute: u32) -> Self {
+        let capacity = requests_per_minute.max(1) as f64;
+        Self {
+            capacity,
+            tokens: Mutex::new(capacity),
+            refill_per_sec: capacity / 60.0,
+            last_refill: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Wait cooperatively until a token is available, then consume it
+    async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut tokens = self.tokens.lock().await;
+                let mut last_refill = self.last_refill.lock().await;
+
+                let elapsed = last_refill.elapsed().as_secs_f64();
+                *tokens = (*tokens + elapsed * self.refill_per_sec).min(self.capacity);
+                *last_refill = Instant::now();
+
+                if *tokens >= 1.0 {
+                    *tokens -= 1.0;
+                    None
+                } else {
+                    let deficit = 1.0 - *tokens;
+                    Some(Duration::from_secs_f64(deficit / self.refill_per_sec))
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(delay) => tokio::time::sleep(delay).await,
+            }
+        }
+    }
+}
+
+/// Decorates an `LLMProvider` with request-rate throttling, a concurrency
+/// cap, and retry-with-backoff on rate-limit errors.
+pub struct RateLimitedProvider {
+    inner: Arc<dyn LLMProvider>,
+    bucket: TokenBucket,
+    concurrency: Arc<Semaphore>,
+    retry_policy: RetryPolicy,
+    retries_observed: AtomicU64,
+}
+
+impl RateLimitedProvider {
+    /// Wrap `inner` with the given rate-limit configuration
+    pub fn new(inner: Arc<dyn LLMProvider>, config: RateLimitConfig) -> Self {
+        Self {
+            bucket: TokenBucket::new(config.requests_per_minute),
+            concurrency: Arc::new(Semaphore::new(config.max_concurrent)),
+            retry_policy: config.retry_policy,
+            inner,
+            retries_observed: AtomicU64::new(0),
+        }
+    }
+
+    /// Wrap `inner` according to `config.rate_limit`, if set; otherwise
+    /// return `inner` unchanged. Lets callers opt into throttling purely
+    /// through `LLMConfig::with_rate_limit` without constructing a
+    /// `RateLimitedProvider` directly.
+    pub fn from_llm_config(inner: Arc<dyn LLMProvider>, config: &LLMConfig) -> Arc<dyn LLMProvider> {
+        match config.rate_limit.clone() {
+            Some(rate_limit) => Arc::new(Self::new(inner, rate_limit)),
+            None => inner,
+        }
+    }
+
+    /// Number of retries this provider has performed so far, for metrics
+    pub fn retries_observed(&self) -> u64 {
+        self.retries_observed.load(Ordering::Relaxed)
+    }
+
+    /// Whether an error looks like a provider rate-limit response. Providers
+    /// surface this as a `DeepAgentError` whose message carries the status;
+    /// this is a best-effort heuristic pending a dedicated error variant.
+    fn is_rate_limited(error: &DeepAgentError) -> bool {
+        let message = error.to_string().to_lowercase();
+        message.contains("429") || message.contains("rate limit") || message.contains("too many requests")
+    }
+
+    async fn throttled<'a, F, Fut, T>(&'a self, call: F) -> Result<T, DeepAgentError>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T, DeepAgentError>> + 'a,
+    {
+        let _permit = self
+            .concurrency
+            .acquire()
+            .await
+            .expect("semaphore is never closed");
+
+        let mut attempt = 0;
+        loop {
+            self.bucket.acquire().await;
+            match call().await {
+                Ok(value) => return Ok(value),
+                Err(e) if Self::is_rate_limited(&e) && attempt + 1 < self.retry_policy.max_attempts => {
+                    self.retries_observed.fetch_add(1, Ordering::Relaxed);
+                    let delay = self.retry_policy.delay_for_attempt(attempt);
+                    tracing::warn!(attempt, ?delay, "LLM provider rate-limited, retrying");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl LLMProvider for RateLimitedProvider {
+    async fn complete(
+        &self,
+        messages: &[Message],
+        tools: &[ToolDefinition],
+        config: Option<&LLMConfig>,
+    ) -> Result<LLMResponse, DeepAgentError> {
+        self.throttled(|| self.inner.complete(messages, tools, config))
+            .await
+    }
+
+    async fn complete_stream(
+        &self,
+        messages: &[Message],
+        tools: &[ToolDefinition],
+        config: Option<&LLMConfig>,
+    ) -> Result<BoxStream<'_, StreamDelta>, DeepAgentError> {
+        // `throttled` releases its permit as soon as the future it's given
+        // resolves — fine for `complete`, where that means the whole
+        // response is in hand, but wrong here: producing a `BoxStream`
+        // resolves immediately, before the stream is ever polled. Acquire
+        // the permit ourselves and move it into the returned stream so it's
+        // held for the stream's entire lifetime instead.
+        let permit = Arc::clone(&self.concurrency)
+            .acquire_owned()
+            .await
+            .expect("semaphore is never closed");
+
+        let mut attempt = 0;
+        let stream = loop {
+            self.bucket.acquire().await;
+            match self.inner.complete_stream(messages, tools, config).await {
+                Ok(stream) => break stream,
+                Err(e) if Self::is_rate_limited(&e) && attempt + 1 < self.retry_policy.max_attempts => {
+                    self.retries_observed.fetch_add(1, Ordering::Relaxed);
+                    let delay = self.retry_policy.delay_for_attempt(attempt);
+                    tracing::warn!(attempt, ?delay, "LLM provider rate-limited, retrying");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        Ok(Box::pin(PermitGuardedStream {
+            inner: stream,
+            _permit: permit,
+        }))
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn default_model(&self) -> &str {
+        self.inner.default_model()
+    }
+}
+
+/// Wraps a provider's stream together with the concurrency permit acquired
+/// for it, so the permit is released only once the stream itself is
+/// dropped (fully consumed or abandoned) rather than as soon as it's
+/// constructed.
+struct PermitGuardedStream<'a> {
+    inner: BoxStream<'a, StreamDelta>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl<'a> Stream for PermitGuardedStream<'a> {
+    type Item = StreamDelta;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().inner.as_mut().poll_next(cx)
+    }
+}
@@ -0,0 +1,132 @@
+//! Tunables for the Pregel runtime
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::pregel::checkpoint::Checkpointer;
+
+/// Runtime-wide configuration for a `PregelRuntime`
+#[derive(Clone)]
+pub struct PregelConfig {
+    /// Maximum number of vertices computed concurrently within a superstep.
+    /// Defaults to the available parallelism of the host.
+    pub max_concurrency: usize,
+    /// Hard cap on the number of supersteps a workflow may run before
+    /// `PregelError::MaxSuperstepsExceeded` is returned.
+    pub max_supersteps: usize,
+    /// A retry policy carried on the config. `PregelRuntime::run` does not
+    /// read it: a failed vertex compute is returned without retry.
+    pub retry_policy: Option<RetryPolicy>,
+    /// Checkpointer attached to every vertex's `ComputeContext`, so
+    /// `checkpoint_intermediate`/`load_intermediate` calls (e.g.
+    /// `AgentVertex`'s mid-loop resume) actually persist instead of being
+    /// no-ops. `None` means no checkpointing.
+    pub checkpointer: Option<Arc<dyn Checkpointer>>,
+}
+
+impl std::fmt::Debug for PregelConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PregelConfig")
+            .field("max_concurrency", &self.max_concurrency)
+            .field("max_supersteps", &self.max_supersteps)
+            .field("retry_policy", &self.retry_policy)
+            .field("checkpointer", &self.checkpointer.as_ref().map(|_| "Checkpointer"))
+            .finish()
+    }
+}
+
+impl Default for PregelConfig {
+    fn default() -> Self {
+        let max_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+
+        Self {
+            max_concurrency,
+            max_supersteps: 1_000,
+            retry_policy: None,
+            checkpointer: None,
+        }
+    }
+}
+
+impl PregelConfig {
+    /// Cap on how many vertices may compute concurrently in one superstep
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Hard cap on the number of supersteps before the runtime gives up
+    pub fn with_max_supersteps(mut self, max_supersteps: usize) -> Self {
+        self.max_supersteps = max_supersteps;
+        self
+    }
+
+    /// Set `retry_policy`; the runtime itself does not retry vertex computes
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Checkpointer attached to every vertex's `ComputeContext`
+    pub fn with_checkpointer(mut self, checkpointer: Arc<dyn Checkpointer>) -> Self {
+        self.checkpointer = Some(checkpointer);
+        self
+    }
+}
+
+/// Exponential backoff with jitter, shared by the parts of the crate that
+/// retry a fallible operation (rate-limited LLM calls, tool execution).
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_delay: Duration,
+    /// Multiplier applied to the delay after each retry
+    pub multiplier: f64,
+    /// Upper bound on the computed delay, regardless of attempt count
+    pub max_delay: Duration,
+    /// Whether to randomize the delay within `[0, computed_delay]` to avoid
+    /// thundering-herd retries
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay: Duration::from_millis(200),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The delay to wait before the given retry attempt (0-indexed: the
+    /// delay before the *first* retry, i.e. after the initial attempt fails)
+    pub fn delay_for_attempt(&self, attempt: u32) -> Duration {
+        let scaled = self.initial_delay.as_secs_f64() * self.multiplier.powi(attempt as i32);
+        let capped = scaled.min(self.max_delay.as_secs_f64());
+        let delay = if self.jitter {
+            capped * fastrand_unit()
+        } else {
+            capped
+        };
+        Duration::from_secs_f64(delay)
+    }
+}
+
+/// A `[0, 1)` pseudo-random value for jitter, without pulling in a full RNG
+/// dependency for a single call site.
+fn fastrand_unit() -> f64 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    (nanos % 1_000) as f64 / 1_000.0
+}
@@ -0,0 +1,34 @@
+//! Error types produced by the Pregel runtime
+
+use crate::pregel::vertex::VertexId;
+
+/// Errors that can occur while running a Pregel workflow
+#[derive(Debug, thiserror::Error)]
+pub enum PregelError {
+    /// A vertex's `compute` call failed
+    #[error("vertex '{vertex_id}' failed: {message}")]
+    VertexError {
+        vertex_id: VertexId,
+        message: String,
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
+
+    /// The runtime exceeded its configured superstep limit without halting
+    #[error("workflow exceeded max supersteps ({0})")]
+    MaxSuperstepsExceeded(usize),
+
+    /// Checkpoint read/write failed
+    #[error("checkpoint error: {0}")]
+    CheckpointError(String),
+}
+
+impl PregelError {
+    /// Construct a `VertexError` with no underlying source error
+    pub fn vertex_error(vertex_id: VertexId, message: impl Into<String>) -> Self {
+        PregelError::VertexError {
+            vertex_id,
+            message: message.into(),
+            source: None,
+        }
+    }
+}
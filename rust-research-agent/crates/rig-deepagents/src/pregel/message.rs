@@ -0,0 +1,13 @@
+//! Message types exchanged between vertices during a superstep
+
+use serde::{Deserialize, Serialize};
+
+/// The payload carried between vertices in a workflow graph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WorkflowMessage {
+    /// A keyed piece of data, e.g. a tool result or an agent response
+    Data {
+        key: String,
+        value: serde_json::Value,
+    },
+}
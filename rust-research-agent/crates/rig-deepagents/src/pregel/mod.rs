@@ -37,9 +37,9 @@ pub mod checkpoint;
 pub use vertex::{
     BoxedVertex, ComputeContext, ComputeResult, StateUpdate, Vertex, VertexId, VertexState,
 };
-pub use message::{Priority, Source, VertexMessage, WorkflowMessage};
+pub use message::WorkflowMessage;
 pub use config::{PregelConfig, RetryPolicy};
 pub use error::PregelError;
-pub use state::{UnitState, UnitUpdate, WorkflowState};
+pub use state::{MapState, MapUpdate, UnitState, UnitUpdate, WorkflowState};
 pub use runtime::{PregelRuntime, WorkflowResult};
 pub use checkpoint::{Checkpoint, Checkpointer, CheckpointerConfig, MemoryCheckpointer, FileCheckpointer, create_checkpointer};
@@ -0,0 +1,186 @@
+//! The Pregel superstep loop: deliver, compute, collect, route.
+//!
+//! Vertices active within a superstep are independent, so their `compute`
+//! calls are dispatched across a bounded worker pool (modeled on a
+//! jobserver: a fixed set of permits, greedily handed to ready vertices and
+//! refilled as they complete) instead of running sequentially. The
+//! superstep barrier itself is preserved: every active vertex's messages are
+//! collected before anything is routed into the next superstep, and that
+//! routing is sorted by source vertex id so delivery order into each
+//! inbox is reproducible regardless of which vertex happened to finish
+//! first.
+
+use futures::stream::{self, StreamExt};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::pregel::config::PregelConfig;
+use crate::pregel::error::PregelError;
+use crate::pregel::state::WorkflowState;
+use crate::pregel::vertex::{BoxedVertex, ComputeContext, Vertex, VertexId, VertexState};
+
+/// Outcome of running a workflow to completion
+#[derive(Debug)]
+pub struct WorkflowResult {
+    /// Number of supersteps actually executed
+    pub supersteps_run: usize,
+}
+
+/// A Pregel-style runtime executing a fixed set of vertices to completion
+pub struct PregelRuntime<S: WorkflowState, M: Send + Sync + Clone + 'static> {
+    vertices: HashMap<VertexId, BoxedVertex<S, M>>,
+    config: PregelConfig,
+}
+
+impl<S: WorkflowState, M: Send + Sync + Clone + 'static> PregelRuntime<S, M> {
+    /// Create an empty runtime with the given configuration
+    pub fn new(config: PregelConfig) -> Self {
+        Self {
+            vertices: HashMap::new(),
+            config,
+        }
+    }
+
+    /// Register a vertex in the graph
+    pub fn add_vertex(&mut self, vertex: BoxedVertex<S, M>) {
+        self.vertices.insert(vertex.id().clone(), vertex);
+    }
+
+    /// Run the workflow to completion: every vertex starts active, and the
+    /// runtime supersteps until no vertex is active (or `max_supersteps` is
+    /// exceeded), merging state updates and routing messages deterministically
+    /// between supersteps.
+    pub async fn run(
+        &self,
+        state: &mut S,
+        initial_messages: HashMap<VertexId, Vec<M>>,
+    ) -> Result<WorkflowResult, PregelError> {
+        let mut inboxes = initial_messages;
+        let mut active: HashSet<VertexId> = self.vertices.keys().cloned().collect();
+        let mut superstep = 0;
+
+        while !active.is_empty() {
+            if superstep >= self.config.max_supersteps {
+                return Err(PregelError::MaxSuperstepsExceeded(self.config.max_supersteps));
+            }
+
+            // Deterministic dispatch order; completion order stays
+            // arbitrary (bounded by `max_concurrency`), but this is the
+            // order results get folded back in below.
+            let mut ids: Vec<VertexId> = active.iter().cloned().collect();
+            ids.sort();
+
+            let state_ref = &*state;
+            let max_concurrency = self.config.max_concurrency;
+            let checkpointer = self.config.checkpointer.clone();
+            let results: Vec<(VertexId, Result<(VertexState, S::Update), PregelError>, HashMap<VertexId, Vec<M>>)> =
+                stream::iter(ids.into_iter().map(|id| {
+                    let vertex: BoxedVertex<S, M> = Arc::clone(self.vertices.get(&id).expect("active vertex must be registered"));
+                    let messages = inboxes.remove(&id).unwrap_or_default();
+                    let checkpointer = checkpointer.clone();
+                    async move {
+                        let mut ctx = ComputeContext::new(id.clone(), &messages, superstep, state_ref);
+                        if let Some(checkpointer) = checkpointer {
+                            ctx = ctx.with_checkpointer(checkpointer);
+                        }
+                        let outcome = vertex.compute(&mut ctx).await;
+                        match outcome {
+                            Ok(result) => {
+                                let outbox = ctx.into_outbox();
+                                (id, Ok((result.state, result.update)), outbox)
+                            }
+                            Err(e) => (id, Err(e), HashMap::new()),
+                        }
+                    }
+                }))
+                .buffer_unordered(max_concurrency)
+                .collect()
+                .await;
+
+            // Fold results back in source-id order so the merge is
+            // reproducible regardless of completion order above.
+            let mut ordered = results;
+            ordered.sort_by(|a, b| a.0.cmp(&b.0));
+
+            let mut next_inboxes: HashMap<VertexId, Vec<M>> = HashMap::new();
+            let mut next_active: HashSet<VertexId> = HashSet::new();
+
+            for (id, outcome, outbox) in ordered {
+                let (vertex_state, update) = outcome?;
+                state.apply(update);
+
+                if vertex_state == VertexState::Active {
+                    next_active.insert(id);
+                }
+
+                for (target, mut messages) in outbox {
+                    next_inboxes.entry(target.clone()).or_default().append(&mut messages);
+                    // Any vertex that receives a message is reactivated,
+                    // even if it had voted to halt.
+                    if self.vertices.contains_key(&target) {
+                        next_active.insert(target);
+                    }
+                }
+            }
+
+            inboxes = next_inboxes;
+            active = next_active;
+            superstep += 1;
+        }
+
+        Ok(WorkflowResult {
+            supersteps_run: superstep,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pregel::checkpoint::{Checkpointer, MemoryCheckpointer};
+    use crate::pregel::state::UnitState;
+
+    // A vertex that checkpoints intermediate state and then halts on every
+    // compute, to prove the runtime actually attaches a
+    // `Checkpointer` to each vertex's `ComputeContext` (without this, its
+    // checkpoint write would silently no-op and the assertion below fails).
+    struct CheckpointingVertex {
+        id: VertexId,
+    }
+
+    #[async_trait::async_trait]
+    impl Vertex<UnitState, ()> for CheckpointingVertex {
+        fn id(&self) -> &VertexId {
+            &self.id
+        }
+
+        async fn compute(
+            &self,
+            ctx: &mut ComputeContext<'_, UnitState, ()>,
+        ) -> Result<crate::pregel::vertex::ComputeResult<crate::pregel::state::UnitUpdate>, PregelError>
+        {
+            ctx.checkpoint_intermediate(&serde_json::json!({"ran": true})).await?;
+            Ok(crate::pregel::vertex::ComputeResult::halt(
+                crate::pregel::state::UnitUpdate,
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn config_checkpointer_is_attached_to_every_vertex_context() {
+        let checkpointer: Arc<dyn Checkpointer> = Arc::new(MemoryCheckpointer::new());
+
+        let mut runtime: PregelRuntime<UnitState, ()> = PregelRuntime::new(
+            PregelConfig::default().with_checkpointer(Arc::clone(&checkpointer)),
+        );
+        runtime.add_vertex(Arc::new(CheckpointingVertex { id: "v1".into() }));
+
+        let mut state = UnitState;
+        runtime.run(&mut state, HashMap::new()).await.unwrap();
+
+        // The vertex's checkpoint write only persists if the runtime handed
+        // it a real checkpointer instead of leaving `ComputeContext` bare.
+        let saved = checkpointer.get_intermediate(&VertexId::new("v1")).await.unwrap();
+        assert!(saved.is_some());
+    }
+}
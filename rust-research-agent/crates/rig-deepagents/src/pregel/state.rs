@@ -0,0 +1,111 @@
+//! Workflow state shared (read-only, per superstep) across all vertices
+
+use std::collections::HashMap;
+
+use crate::pregel::vertex::StateUpdate;
+
+/// The state threaded through a Pregel workflow. Implementations expose
+/// whatever fields a workflow's vertices need to read and merge updates into.
+pub trait WorkflowState: Send + Sync + 'static {
+    /// The incremental update type vertices produce when they touch state
+    type Update: StateUpdate;
+
+    /// Apply an update produced by a vertex's compute call
+    fn apply(&mut self, update: Self::Update);
+
+    /// Look up a dotted-path field for `StopCondition::StateMatch` and
+    /// similar state-driven predicates. `None` means the path doesn't
+    /// resolve to anything (missing key, wrong shape, or a state type that
+    /// doesn't expose queryable fields at all).
+    ///
+    /// The default implementation exposes nothing; state types with
+    /// queryable fields (e.g. [`MapState`]) should override it.
+    fn get_path(&self, _path: &str) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+/// Walk a dotted path (`"a.b.c"`) into a `serde_json::Value`, indexing
+/// objects by key and arrays by a segment that parses as a `usize`.
+pub(crate) fn resolve_json_path(value: &serde_json::Value, path: &str) -> Option<serde_json::Value> {
+    let mut current = value;
+    for segment in path.split('.') {
+        current = match current {
+            serde_json::Value::Object(map) => map.get(segment)?,
+            serde_json::Value::Array(items) => items.get(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    Some(current.clone())
+}
+
+/// A no-op state update, for workflows that don't use shared state
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnitUpdate;
+
+impl StateUpdate for UnitUpdate {
+    fn empty() -> Self {
+        UnitUpdate
+    }
+}
+
+/// A no-op workflow state, for workflows whose vertices don't share state
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnitState;
+
+impl WorkflowState for UnitState {
+    type Update = UnitUpdate;
+
+    fn apply(&mut self, _update: Self::Update) {}
+}
+
+/// An update to a [`MapState`]: a set of dotted paths to overwrite at the
+/// top level of the map (nested paths are written verbatim as the key, not
+/// merged into existing nested objects).
+#[derive(Debug, Clone, Default)]
+pub struct MapUpdate(pub Vec<(String, serde_json::Value)>);
+
+impl MapUpdate {
+    /// Convenience constructor for a single key/value write
+    pub fn set(key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        Self(vec![(key.into(), value.into())])
+    }
+}
+
+impl StateUpdate for MapUpdate {
+    fn empty() -> Self {
+        MapUpdate(Vec::new())
+    }
+}
+
+/// A general-purpose workflow state backed by a flat map of JSON values,
+/// queryable by dotted path (e.g. `"research.query"`) for
+/// `StopCondition::StateMatch`.
+#[derive(Debug, Clone, Default)]
+pub struct MapState(HashMap<String, serde_json::Value>);
+
+impl MapState {
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl WorkflowState for MapState {
+    type Update = MapUpdate;
+
+    fn apply(&mut self, update: Self::Update) {
+        for (key, value) in update.0 {
+            self.0.insert(key, value);
+        }
+    }
+
+    fn get_path(&self, path: &str) -> Option<serde_json::Value> {
+        let (head, rest) = path.split_once('.').unwrap_or((path, ""));
+        let root = self.0.get(head)?;
+        if rest.is_empty() {
+            Some(root.clone())
+        } else {
+            resolve_json_path(root, rest)
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! Core vertex abstractions for the Pregel runtime
+//!
+//! Defines the [`Vertex`] trait that every computation node implements, along
+//! with the context/result types threaded through a superstep.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::pregel::checkpoint::Checkpointer;
+use crate::pregel::error::PregelError;
+use crate::pregel::state::WorkflowState;
+
+/// Identifier for a vertex within a workflow graph
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VertexId(String);
+
+impl VertexId {
+    /// Create a new vertex id from anything string-like
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// Borrow the id as a plain string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for VertexId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for VertexId {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for VertexId {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Whether a vertex wants to keep running next superstep or has finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexState {
+    /// The vertex may still receive messages and compute again
+    Active,
+    /// The vertex has finished; it is scheduled again only if it receives a
+    /// message
+    Halted,
+}
+
+impl VertexState {
+    /// True if this vertex has halted
+    pub fn is_halted(&self) -> bool {
+        matches!(self, VertexState::Halted)
+    }
+}
+
+/// Per-workflow-state incremental update produced by a single vertex compute
+pub trait StateUpdate: Send + Sync + 'static {
+    /// An update that changes nothing, for vertices that don't touch state
+    fn empty() -> Self;
+}
+
+/// Result of a single `Vertex::compute` invocation
+pub struct ComputeResult<U> {
+    /// Whether the vertex should be scheduled again next superstep
+    pub state: VertexState,
+    /// Incremental state update to merge into the workflow state
+    pub update: U,
+}
+
+impl<U: StateUpdate> ComputeResult<U> {
+    /// The vertex is done and should not run again
+    pub fn halt(update: U) -> Self {
+        Self {
+            state: VertexState::Halted,
+            update,
+        }
+    }
+
+    /// The vertex wants to be scheduled again next superstep
+    pub fn active(update: U) -> Self {
+        Self {
+            state: VertexState::Active,
+            update,
+        }
+    }
+}
+
+/// Everything a vertex needs to compute for one superstep: the messages
+/// delivered to it, the current (read-only) workflow state, and a place to
+/// deposit outgoing messages for the next superstep.
+pub struct ComputeContext<'a, S: WorkflowState, M> {
+    /// This vertex's own id, for messages it sends to itself/logging
+    pub id: VertexId,
+    /// Messages delivered to this vertex for the current superstep
+    pub messages: &'a [M],
+    /// Index of the current superstep
+    pub superstep: usize,
+    /// Read-only view of the workflow state
+    pub state: &'a S,
+    outbox: HashMap<VertexId, Vec<M>>,
+    checkpointer: Option<Arc<dyn Checkpointer>>,
+}
+
+impl<'a, S: WorkflowState, M> ComputeContext<'a, S, M> {
+    /// Build a new context for a single vertex compute call
+    pub fn new(id: VertexId, messages: &'a [M], superstep: usize, state: &'a S) -> Self {
+        Self {
+            id,
+            messages,
+            superstep,
+            state,
+            outbox: HashMap::new(),
+            checkpointer: None,
+        }
+    }
+
+    /// Attach a checkpointer so the vertex can call [`Self::checkpoint_intermediate`]
+    /// and [`Self::load_intermediate`] during `compute`
+    pub fn with_checkpointer(mut self, checkpointer: Arc<dyn Checkpointer>) -> Self {
+        self.checkpointer = Some(checkpointer);
+        self
+    }
+
+    /// Queue a message for delivery to `target` at the start of the next superstep
+    pub fn send_message(&mut self, target: impl Into<VertexId>, message: M) {
+        self.outbox.entry(target.into()).or_default().push(message);
+    }
+
+    /// Whether any messages were delivered to this vertex this superstep
+    pub fn has_messages(&self) -> bool {
+        !self.messages.is_empty()
+    }
+
+    /// Consume the context, returning everything queued via `send_message`
+    pub fn into_outbox(self) -> HashMap<VertexId, Vec<M>> {
+        self.outbox
+    }
+
+    /// Persist a mid-computation snapshot for this vertex, keyed by its
+    /// `VertexId`, so a failed/restarted compute can resume instead of
+    /// rebuilding from scratch. A no-op if no checkpointer is attached.
+    pub async fn checkpoint_intermediate<T: serde::Serialize + Sync>(
+        &self,
+        data: &T,
+    ) -> Result<(), PregelError> {
+        let Some(checkpointer) = &self.checkpointer else {
+            return Ok(());
+        };
+        let value = serde_json::to_value(data)
+            .map_err(|e| PregelError::CheckpointError(e.to_string()))?;
+        checkpointer.put_intermediate(&self.id, value).await
+    }
+
+    /// Load a previously checkpointed snapshot for this vertex, if any
+    pub async fn load_intermediate<T: serde::de::DeserializeOwned>(
+        &self,
+    ) -> Result<Option<T>, PregelError> {
+        let Some(checkpointer) = &self.checkpointer else {
+            return Ok(None);
+        };
+        match checkpointer.get_intermediate(&self.id).await? {
+            Some(value) => {
+                let parsed = serde_json::from_value(value)
+                    .map_err(|e| PregelError::CheckpointError(e.to_string()))?;
+                Ok(Some(parsed))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Clear this vertex's checkpointed snapshot, e.g. once its loop halts
+    pub async fn clear_intermediate(&self) -> Result<(), PregelError> {
+        let Some(checkpointer) = &self.checkpointer else {
+            return Ok(());
+        };
+        checkpointer.clear_intermediate(&self.id).await
+    }
+}
+
+/// A computation unit in the Pregel graph: agent, tool, router, etc.
+#[async_trait]
+pub trait Vertex<S: WorkflowState, M: Send + Sync>: Send + Sync {
+    /// This vertex's stable identifier within the graph
+    fn id(&self) -> &VertexId;
+
+    /// Compute one superstep: consume delivered messages, optionally touch
+    /// state, and queue outgoing messages for the next superstep.
+    async fn compute(
+        &self,
+        ctx: &mut ComputeContext<'_, S, M>,
+    ) -> Result<ComputeResult<S::Update>, PregelError>;
+}
+
+/// A type-erased, heap-allocated vertex, for graphs that mix vertex types
+pub type BoxedVertex<S, M> = Arc<dyn Vertex<S, M>>;
@@ -0,0 +1,8 @@
+//! Workflow definitions: node configuration and the vertices that execute them
+//!
+//! A workflow is authored as a graph of nodes (see [`node`]) and compiled
+//! down to Pregel [`crate::pregel::vertex::Vertex`] implementations (see
+//! [`vertices`]) for execution.
+
+pub mod node;
+pub mod vertices;
@@ -0,0 +1,125 @@
+//! Node-level configuration for workflow graphs
+//!
+//! Nodes are the author-facing configuration authored into a workflow graph;
+//! each is compiled into a corresponding vertex in [`super::vertices`]
+//! (e.g. `AgentNodeConfig` -> `AgentVertex`, `ToolNodeConfig` -> `ToolVertex`).
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::pregel::config::RetryPolicy;
+
+/// Configuration for a single tool-execution node
+#[derive(Debug, Clone)]
+pub struct ToolNodeConfig {
+    /// Name of the tool to execute, as registered with the runtime
+    pub tool_name: String,
+
+    /// Arguments fixed at config time
+    pub static_args: HashMap<String, Value>,
+
+    /// Arguments resolved from workflow state at execution time, keyed by
+    /// argument name to a dotted path into the state
+    /// (see [`crate::pregel::state::WorkflowState::get_path`]). Wins over
+    /// `static_args` on key collision.
+    pub state_arg_paths: HashMap<String, String>,
+
+    /// Where to store the tool's result in the output message; defaults to
+    /// `"{tool_name}_result"` when unset
+    pub result_path: Option<String>,
+
+    /// Downgrade a schema-validation failure to a `tracing::warn!` instead
+    /// of a terminal error, for tools with loose or unreliable schemas
+    pub lenient_validation: bool,
+
+    /// Retry-with-backoff policy applied to a failed execution. `None`
+    /// means the tool is executed exactly once.
+    pub retry_policy: Option<RetryPolicy>,
+
+    /// Per-attempt timeout wrapping tool execution; attempts that exceed it
+    /// are treated as a (retriable) timeout error
+    pub tool_timeout: Option<Duration>,
+}
+
+impl Default for ToolNodeConfig {
+    fn default() -> Self {
+        Self {
+            tool_name: String::new(),
+            static_args: HashMap::new(),
+            state_arg_paths: HashMap::new(),
+            result_path: None,
+            lenient_validation: false,
+            retry_policy: None,
+            tool_timeout: None,
+        }
+    }
+}
+
+/// Configuration for an LLM-backed agent node
+#[derive(Debug, Clone)]
+pub struct AgentNodeConfig {
+    /// System prompt seeding the agent's message history
+    pub system_prompt: String,
+
+    /// Conditions checked after each assistant turn; the agent halts as
+    /// soon as any one of them is met
+    pub stop_conditions: Vec<StopCondition>,
+
+    /// Hard cap on the number of LLM turns before the vertex fails with
+    /// `PregelError::VertexError`
+    pub max_iterations: usize,
+
+    /// Restricts which tools the agent may call; `None` allows all tools
+    /// passed to the vertex
+    pub allowed_tools: Option<Vec<String>>,
+
+    /// Sampling temperature passed through to the LLM provider
+    pub temperature: Option<f32>,
+}
+
+impl Default for AgentNodeConfig {
+    fn default() -> Self {
+        Self {
+            system_prompt: String::new(),
+            stop_conditions: Vec::new(),
+            max_iterations: 10,
+            allowed_tools: None,
+            temperature: None,
+        }
+    }
+}
+
+/// A condition that ends an agent's turn-taking loop
+#[derive(Debug, Clone)]
+pub enum StopCondition {
+    /// The assistant turn made no tool calls
+    NoToolCalls,
+
+    /// The assistant called a specific tool
+    OnTool {
+        /// Name of the tool that triggers the stop
+        tool_name: String,
+    },
+
+    /// The assistant's response content contains a substring
+    ContainsText {
+        /// Substring to search for in the response content
+        pattern: String,
+    },
+
+    /// A fixed number of iterations has been reached
+    MaxIterations {
+        /// Iteration count (0-indexed) at which to stop
+        count: usize,
+    },
+
+    /// A dotted path into workflow state resolves to an expected value
+    StateMatch {
+        /// Dotted path resolved via `WorkflowState::get_path`
+        path: String,
+        /// Value the resolved path must equal to trigger the stop
+        expected: Value,
+    },
+}
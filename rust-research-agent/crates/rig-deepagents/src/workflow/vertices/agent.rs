@@ -6,6 +6,9 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 
+use futures::future::join_all;
+use futures::StreamExt;
+
 use crate::llm::{LLMConfig, LLMProvider};
 use crate::middleware::ToolDefinition;
 use crate::pregel::error::PregelError;
@@ -14,6 +17,17 @@ use crate::pregel::state::WorkflowState;
 use crate::pregel::vertex::{ComputeContext, ComputeResult, StateUpdate, Vertex, VertexId};
 use crate::state::{Message, Role};
 use crate::workflow::node::{AgentNodeConfig, StopCondition};
+use crate::workflow::vertices::streaming::StreamAssembler;
+use crate::workflow::vertices::tool_registry::ToolRegistry;
+
+/// Mid-computation snapshot of an `AgentVertex`'s loop, checkpointed after
+/// each iteration so a failed/restarted compute resumes instead of
+/// re-running (and re-billing) every prior LLM/tool call.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct AgentLoopState {
+    messages: Vec<Message>,
+    iteration: usize,
+}
 
 /// An agent vertex that uses an LLM to process messages and call tools
 pub struct AgentVertex<S: WorkflowState> {
@@ -21,6 +35,7 @@ pub struct AgentVertex<S: WorkflowState> {
     config: AgentNodeConfig,
     llm: Arc<dyn LLMProvider>,
     tools: Vec<ToolDefinition>,
+    tool_registry: Arc<ToolRegistry>,
     _phantom: std::marker::PhantomData<S>,
 }
 
@@ -31,18 +46,20 @@ impl<S: WorkflowState> AgentVertex<S> {
         config: AgentNodeConfig,
         llm: Arc<dyn LLMProvider>,
         tools: Vec<ToolDefinition>,
+        tool_registry: Arc<ToolRegistry>,
     ) -> Self {
         Self {
             id: id.into(),
             config,
             llm,
             tools,
+            tool_registry,
             _phantom: std::marker::PhantomData,
         }
     }
 
     /// Check if any stop condition is met
-    fn check_stop_conditions(&self, message: &Message, iteration: usize) -> bool {
+    fn check_stop_conditions(&self, message: &Message, iteration: usize, state: &S) -> bool {
         for condition in &self.config.stop_conditions {
             match condition {
                 StopCondition::NoToolCalls => {
@@ -67,9 +84,10 @@ impl<S: WorkflowState> AgentVertex<S> {
                         return true;
                     }
                 }
-                StopCondition::StateMatch { .. } => {
-                    // TODO: Implement state matching
-                    continue;
+                StopCondition::StateMatch { path, expected } => {
+                    if state.get_path(path).as_ref() == Some(expected) {
+                        return true;
+                    }
                 }
             }
         }
@@ -93,19 +111,10 @@ impl<S: WorkflowState> AgentVertex<S> {
     fn build_llm_config(&self) -> Option<LLMConfig> {
         self.config.temperature.map(|temp| LLMConfig::new("").with_temperature(temp as f64))
     }
-}
-
-#[async_trait]
-impl<S: WorkflowState> Vertex<S, WorkflowMessage> for AgentVertex<S> {
-    fn id(&self) -> &VertexId {
-        &self.id
-    }
 
-    async fn compute(
-        &self,
-        ctx: &mut ComputeContext<'_, S, WorkflowMessage>,
-    ) -> Result<ComputeResult<S::Update>, PregelError> {
-        // Build message history starting with system prompt
+    /// Build the starting message history: the system prompt plus any
+    /// incoming workflow messages (or a default activation message if none)
+    fn build_initial_messages(&self, ctx: &ComputeContext<'_, S, WorkflowMessage>) -> Vec<Message> {
         let mut messages = vec![Message {
             role: Role::System,
             content: self.config.system_prompt.clone(),
@@ -113,7 +122,6 @@ impl<S: WorkflowState> Vertex<S, WorkflowMessage> for AgentVertex<S> {
             tool_call_id: None,
         }];
 
-        // Add any incoming workflow messages as user messages
         for msg in ctx.messages {
             if let WorkflowMessage::Data { key: _, value } = msg {
                 messages.push(Message {
@@ -125,7 +133,6 @@ impl<S: WorkflowState> Vertex<S, WorkflowMessage> for AgentVertex<S> {
             }
         }
 
-        // If no user messages, add a default activation message
         if messages.len() == 1 {
             messages.push(Message {
                 role: Role::User,
@@ -135,27 +142,132 @@ impl<S: WorkflowState> Vertex<S, WorkflowMessage> for AgentVertex<S> {
             });
         }
 
-        let filtered_tools = self.filter_tools();
-        let llm_config = self.build_llm_config();
+        messages
+    }
 
-        // Agent loop: iterate until stop condition or max iterations
-        for iteration in 0..self.config.max_iterations {
-            // Call LLM
-            let response = self
-                .llm
-                .complete(&messages, &filtered_tools, llm_config.as_ref())
+    /// Whether `tool_name` is permitted by `allowed_tools`, if configured
+    fn is_tool_allowed(&self, tool_name: &str) -> bool {
+        match &self.config.allowed_tools {
+            Some(allowed) => allowed.iter().any(|t| t == tool_name),
+            None => true,
+        }
+    }
+
+    /// Consume one streamed assistant turn, emitting partial content as
+    /// intermediate `WorkflowMessage::Data` messages on the "stream" port
+    /// while assembling the final, consolidated `Message`.
+    async fn stream_turn(
+        &self,
+        messages: &[Message],
+        tools: &[ToolDefinition],
+        llm_config: Option<&LLMConfig>,
+        ctx: &mut ComputeContext<'_, S, WorkflowMessage>,
+    ) -> Result<Message, PregelError> {
+        let mut deltas = self
+            .llm
+            .complete_stream(messages, tools, llm_config)
+            .await
+            .map_err(|e| PregelError::VertexError {
+                vertex_id: self.id.clone(),
+                message: e.to_string(),
+                source: Some(Box::new(e)),
+            })?;
+
+        let mut assembler = StreamAssembler::new();
+        while let Some(delta) = deltas.next().await {
+            assembler.push(&delta);
+            if let Some(fragment) = &delta.content {
+                ctx.send_message(
+                    "stream",
+                    WorkflowMessage::Data {
+                        key: "partial_response".to_string(),
+                        value: serde_json::Value::String(fragment.clone()),
+                    },
+                );
+            }
+        }
+
+        let (content, tool_calls) = assembler.finish().map_err(|e| {
+            PregelError::vertex_error(
+                self.id.clone(),
+                format!("malformed tool-call stream: {e}"),
+            )
+        })?;
+
+        Ok(Message {
+            role: Role::Assistant,
+            content,
+            tool_calls: if tool_calls.is_empty() {
+                None
+            } else {
+                Some(tool_calls)
+            },
+            tool_call_id: None,
+        })
+    }
+
+    /// Run every tool call from one assistant turn concurrently, turning each
+    /// into a `tool`-role message carrying its JSON result. A disallowed tool
+    /// or a failed execution makes the whole call return a `PregelError`.
+    async fn execute_tool_calls(
+        &self,
+        tool_calls: &[crate::state::ToolCall],
+    ) -> Result<Vec<Message>, PregelError> {
+        let futures = tool_calls.iter().map(|tool_call| async move {
+            if !self.is_tool_allowed(&tool_call.name) {
+                return Err(PregelError::vertex_error(
+                    self.id.clone(),
+                    format!("tool '{}' is not in allowed_tools", tool_call.name),
+                ));
+            }
+
+            let result = self
+                .tool_registry
+                .execute(&tool_call.name, &tool_call.arguments)
                 .await
                 .map_err(|e| PregelError::VertexError {
                     vertex_id: self.id.clone(),
-                    message: e.to_string(),
+                    message: format!("tool '{}' failed: {}", tool_call.name, e),
                     source: Some(Box::new(e)),
                 })?;
 
-            let assistant_message = response.message.clone();
+            Ok(Message::tool(&result.to_string(), &tool_call.id))
+        });
+
+        join_all(futures).await.into_iter().collect()
+    }
+}
+
+#[async_trait]
+impl<S: WorkflowState> Vertex<S, WorkflowMessage> for AgentVertex<S> {
+    fn id(&self) -> &VertexId {
+        &self.id
+    }
+
+    async fn compute(
+        &self,
+        ctx: &mut ComputeContext<'_, S, WorkflowMessage>,
+    ) -> Result<ComputeResult<S::Update>, PregelError> {
+        // Resume from a checkpointed loop state if one exists (e.g. this
+        // vertex failed partway through a prior attempt), otherwise build
+        // fresh message history starting with the system prompt.
+        let (mut messages, start_iteration) = match ctx.load_intermediate::<AgentLoopState>().await? {
+            Some(checkpoint) => (checkpoint.messages, checkpoint.iteration),
+            None => (self.build_initial_messages(ctx), 0),
+        };
+
+        let filtered_tools = self.filter_tools();
+        let llm_config = self.build_llm_config();
+
+        // Agent loop: iterate until stop condition or max iterations
+        for iteration in start_iteration..self.config.max_iterations {
+            let assistant_message = self
+                .stream_turn(&messages, &filtered_tools, llm_config.as_ref(), ctx)
+                .await?;
             messages.push(assistant_message.clone());
 
             // Check stop conditions
-            if self.check_stop_conditions(&assistant_message, iteration) {
+            if self.check_stop_conditions(&assistant_message, iteration, ctx.state) {
                 // Send final response as output message
                 ctx.send_message(
                     "output",
@@ -164,19 +276,23 @@ impl<S: WorkflowState> Vertex<S, WorkflowMessage> for AgentVertex<S> {
                         value: serde_json::Value::String(assistant_message.content),
                     },
                 );
+                ctx.clear_intermediate().await?;
                 return Ok(ComputeResult::halt(S::Update::empty()));
             }
 
-            // If there are tool calls, execute them
+            // If there are tool calls, dispatch them concurrently through the
+            // tool registry and feed the results back for the next iteration
             if let Some(tool_calls) = &assistant_message.tool_calls {
-                for tool_call in tool_calls {
-                    // TODO: Execute tool calls
-                    // For now, just add a mock tool result
-                    messages.push(Message::tool(
-                        "Tool executed successfully",
-                        &tool_call.id,
-                    ));
-                }
+                let tool_messages = self.execute_tool_calls(tool_calls).await?;
+                messages.extend(tool_messages);
+
+                // Persist accumulated progress before re-billing the next
+                // LLM/tool round, so a failure here can resume mid-loop.
+                ctx.checkpoint_intermediate(&AgentLoopState {
+                    messages: messages.clone(),
+                    iteration: iteration + 1,
+                })
+                .await?;
             } else {
                 // No tool calls and no stop condition matched, halt anyway
                 ctx.send_message(
@@ -186,6 +302,7 @@ impl<S: WorkflowState> Vertex<S, WorkflowMessage> for AgentVertex<S> {
                         value: serde_json::Value::String(assistant_message.content),
                     },
                 );
+                ctx.clear_intermediate().await?;
                 return Ok(ComputeResult::halt(S::Update::empty()));
             }
         }
@@ -206,6 +323,7 @@ mod tests {
     use crate::pregel::state::UnitState;
     use crate::pregel::vertex::VertexState;
     use crate::state::ToolCall;
+    use crate::workflow::vertices::tool_registry::ToolExecutor;
     use std::sync::Mutex;
 
     // Mock LLM provider for testing
@@ -274,6 +392,24 @@ mod tests {
         }
     }
 
+    // Mock executor that always succeeds with a fixed JSON payload
+    struct MockToolExecutor;
+
+    #[async_trait]
+    impl ToolExecutor for MockToolExecutor {
+        async fn execute(
+            &self,
+            _name: &str,
+            _args: &serde_json::Value,
+        ) -> Result<serde_json::Value, DeepAgentError> {
+            Ok(serde_json::json!({"status": "ok"}))
+        }
+    }
+
+    fn empty_registry() -> Arc<ToolRegistry> {
+        Arc::new(ToolRegistry::new())
+    }
+
     #[tokio::test]
     async fn test_agent_vertex_single_response() {
         let mock_llm = MockLLMProvider::new().with_response("Hello! How can I help?");
@@ -287,6 +423,7 @@ mod tests {
             },
             Arc::new(mock_llm),
             vec![],
+            empty_registry(),
         );
 
         let mut ctx =
@@ -313,6 +450,7 @@ mod tests {
             },
             Arc::new(mock_llm),
             vec![],
+            empty_registry(),
         );
 
         let mut ctx =
@@ -341,6 +479,7 @@ mod tests {
             },
             Arc::new(mock_llm),
             vec![],
+            Arc::new(ToolRegistry::new().with_tool("think", Arc::new(MockToolExecutor))),
         );
 
         let mut ctx =
@@ -351,4 +490,129 @@ mod tests {
         // Should hit max iterations and return error
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_agent_vertex_halts_on_state_match() {
+        use crate::pregel::state::{MapState, MapUpdate};
+
+        let mock_llm = MockLLMProvider::new().with_response("Research complete.");
+
+        let vertex = AgentVertex::<MapState>::new(
+            "agent",
+            AgentNodeConfig {
+                system_prompt: "You are a researcher.".into(),
+                stop_conditions: vec![StopCondition::StateMatch {
+                    path: "research.done".to_string(),
+                    expected: serde_json::json!(true),
+                }],
+                ..Default::default()
+            },
+            Arc::new(mock_llm),
+            vec![],
+            empty_registry(),
+        );
+
+        // Before a prior superstep writes the flag, the condition doesn't match.
+        let mut unset_state = MapState::new();
+        let mut ctx = ComputeContext::<MapState, WorkflowMessage>::new(
+            "agent".into(),
+            &[],
+            0,
+            &unset_state,
+        );
+        assert!(!vertex.check_stop_conditions(
+            &Message {
+                role: Role::Assistant,
+                content: "still going".to_string(),
+                tool_calls: None,
+                tool_call_id: None,
+            },
+            0,
+            ctx.state,
+        ));
+        drop(ctx);
+
+        // Simulate a prior superstep applying a `StateUpdate` that writes the flag.
+        unset_state.apply(MapUpdate::set("research", serde_json::json!({"done": true})));
+
+        let mut ctx = ComputeContext::<MapState, WorkflowMessage>::new(
+            "agent".into(),
+            &[],
+            1,
+            &unset_state,
+        );
+        assert!(vertex.check_stop_conditions(
+            &Message {
+                role: Role::Assistant,
+                content: "still going".to_string(),
+                tool_calls: None,
+                tool_call_id: None,
+            },
+            1,
+            ctx.state,
+        ));
+        let result = vertex.compute(&mut ctx).await.unwrap();
+
+        assert_eq!(result.state, VertexState::Halted);
+    }
+
+    #[tokio::test]
+    async fn test_agent_vertex_resumes_from_intermediate_checkpoint() {
+        use crate::pregel::checkpoint::{Checkpointer, MemoryCheckpointer};
+
+        let checkpointer: Arc<dyn Checkpointer> = Arc::new(MemoryCheckpointer::new());
+        let tool_registry = Arc::new(ToolRegistry::new().with_tool("search", Arc::new(MockToolExecutor)));
+
+        // First attempt: only one LLM response is queued, so the loop fails
+        // on its second turn — but only after checkpointing progress from
+        // the first.
+        let mock_llm = MockLLMProvider::new().with_tool_call("Let me search", "search");
+        let vertex = AgentVertex::<UnitState>::new(
+            "agent",
+            AgentNodeConfig {
+                system_prompt: "You are a researcher.".into(),
+                stop_conditions: vec![StopCondition::NoToolCalls],
+                ..Default::default()
+            },
+            Arc::new(mock_llm),
+            vec![],
+            tool_registry.clone(),
+        );
+
+        let mut ctx = ComputeContext::<UnitState, WorkflowMessage>::new("agent".into(), &[], 0, &UnitState)
+            .with_checkpointer(checkpointer.clone());
+        assert!(vertex.compute(&mut ctx).await.is_err());
+
+        // Progress from the failed attempt was persisted.
+        let saved = checkpointer.get_intermediate(&VertexId::new("agent")).await.unwrap();
+        assert!(saved.is_some());
+
+        // Second attempt, same vertex id and checkpointer: only needs a
+        // response for the turn that actually failed last time.
+        let mock_llm_resumed = MockLLMProvider::new().with_response("Done searching.");
+        let vertex_resumed = AgentVertex::<UnitState>::new(
+            "agent",
+            AgentNodeConfig {
+                system_prompt: "You are a researcher.".into(),
+                stop_conditions: vec![StopCondition::NoToolCalls],
+                ..Default::default()
+            },
+            Arc::new(mock_llm_resumed),
+            vec![],
+            tool_registry,
+        );
+
+        let mut ctx_resumed =
+            ComputeContext::<UnitState, WorkflowMessage>::new("agent".into(), &[], 1, &UnitState)
+                .with_checkpointer(checkpointer.clone());
+        let result = vertex_resumed.compute(&mut ctx_resumed).await.unwrap();
+
+        assert_eq!(result.state, VertexState::Halted);
+        // Checkpoint is cleared once the loop halts successfully.
+        assert!(checkpointer
+            .get_intermediate(&VertexId::new("agent"))
+            .await
+            .unwrap()
+            .is_none());
+    }
 }
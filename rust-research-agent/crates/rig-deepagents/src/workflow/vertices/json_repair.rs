@@ -0,0 +1,122 @@
+//! Tolerant JSON parsing for LLM/tool output that's truncated or slightly
+//! malformed (common with streamed tool-call arguments and some tools'
+//! result payloads).
+//!
+//! [`parse_lenient`] tries a strict parse first and only falls back to
+//! bracket-completion repair on failure, so well-formed JSON never pays the
+//! repair cost.
+
+use serde_json::Value;
+
+/// Parse `text` as JSON, repairing common truncation issues if the strict
+/// parse fails. Falls back to `Value::String(text)` if even the repaired
+/// text doesn't parse.
+pub fn parse_lenient(text: &str) -> Value {
+    if let Ok(value) = serde_json::from_str(text) {
+        return value;
+    }
+
+    match repair_json(text) {
+        Some(repaired) => serde_json::from_str(&repaired).unwrap_or_else(|_| Value::String(text.to_string())),
+        None => Value::String(text.to_string()),
+    }
+}
+
+/// Attempt to repair truncated JSON by tracking open brackets/strings and
+/// closing whatever is still open at EOF.
+///
+/// Returns `None` if the text isn't worth attempting to repair (empty), or
+/// `Some(repaired_text)` otherwise — the caller still needs to parse it, as
+/// the repair is purely structural and doesn't guarantee valid JSON (e.g. a
+/// dangling key with no value).
+pub fn repair_json(text: &str) -> Option<String> {
+    let trimmed = text.trim_end();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let mut stack = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for ch in trimmed.chars() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match ch {
+            '"' => in_string = true,
+            '{' => stack.push('}'),
+            '[' => stack.push(']'),
+            '}' | ']' => {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+
+    // Close an open string *before* stripping trailing commas: a comma
+    // inside an unterminated string is string content, not a dangling
+    // separator, and stripping it first would corrupt the value (e.g.
+    // `{"a":"x,` must repair to `{"a":"x,"}`, not `{"a":"x"}`).
+    let mut repaired = trimmed.to_string();
+
+    if in_string {
+        repaired.push('"');
+    } else {
+        repaired = repaired.trim_end_matches(',').to_string();
+    }
+
+    while let Some(closer) = stack.pop() {
+        repaired.push(closer);
+    }
+
+    Some(repaired)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_well_formed_json_without_repair() {
+        assert_eq!(parse_lenient(r#"{"a": 1}"#), serde_json::json!({"a": 1}));
+    }
+
+    #[test]
+    fn repairs_truncated_object() {
+        assert_eq!(parse_lenient(r#"{"a": 1, "b": "two""#), serde_json::json!({"a": 1, "b": "two"}));
+    }
+
+    #[test]
+    fn repairs_truncated_nested_array_and_string() {
+        let truncated = r#"{"items": ["one", "tw"#;
+        let repaired = parse_lenient(truncated);
+        assert_eq!(repaired, serde_json::json!({"items": ["one", "tw"]}));
+    }
+
+    #[test]
+    fn strips_trailing_comma_before_closing() {
+        assert_eq!(parse_lenient(r#"{"a": 1,"#), serde_json::json!({"a": 1}));
+    }
+
+    #[test]
+    fn preserves_comma_that_is_string_content_not_a_separator() {
+        // Truncated mid-string, right after a comma that's part of the
+        // string's own content - the comma must NOT be stripped.
+        let truncated = r#"{"a": "x,"#;
+        assert_eq!(parse_lenient(truncated), serde_json::json!({"a": "x,"}));
+    }
+
+    #[test]
+    fn falls_back_to_string_when_unrepairable() {
+        assert_eq!(parse_lenient("not json at all"), Value::String("not json at all".to_string()));
+    }
+}
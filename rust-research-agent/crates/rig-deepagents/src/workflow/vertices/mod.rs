@@ -7,12 +7,18 @@
 //! - [`agent::AgentVertex`]: LLM-based agent with tool calling
 //! - [`subagent::SubAgentVertex`]: Delegates to sub-agents from registry
 //! - [`tool::ToolVertex`]: Single tool execution with static/dynamic args
+//! - [`tool_registry::ToolRegistry`]: Maps tool names to executors for `AgentVertex`
+//! - [`streaming`]: Incremental assembly of streamed LLM completions
+//! - [`json_repair`]: Tolerant JSON parsing for truncated LLM/tool output
+//! - [`parallel::ParallelToolVertex`]: Bounded concurrent fan-out over several tools in one superstep
+//! - [`schema_validation`]: Validates assembled tool arguments against a tool's parameter JSON Schema
 
 pub mod agent;
+pub mod json_repair;
 pub mod parallel;
+pub mod schema_validation;
+pub mod streaming;
 pub mod subagent;
-// pub mod tool;
+pub mod tool;
+pub mod tool_registry;
 // pub mod router;
-
-// Future vertex implementations:
-// pub mod parallel;
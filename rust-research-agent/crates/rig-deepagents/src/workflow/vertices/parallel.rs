@@ -0,0 +1,280 @@
+//! ParallelToolVertex: fan out several independent tool calls in one superstep
+//!
+//! A sibling to [`ToolVertex`](crate::workflow::vertices::tool::ToolVertex)
+//! for workflows that need several independent searches/retrievals at once
+//! instead of chaining N sequential tool vertices. Each tool still resolves
+//! its own `static_args`/`state_arg_paths` the same way `ToolVertex` does,
+//! and every tool in the set runs concurrently, bounded by a configurable
+//! max-in-flight limit. Unlike `ToolVertex`, each tool is executed exactly
+//! once: arguments are not validated against the tool's schema, and the
+//! config's `retry_policy`, `tool_timeout` and `lenient_validation` are not
+//! applied.
+
+use async_trait::async_trait;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+use crate::middleware::DynTool;
+use crate::pregel::error::PregelError;
+use crate::pregel::message::WorkflowMessage;
+use crate::pregel::state::WorkflowState;
+use crate::pregel::vertex::{ComputeContext, ComputeResult, StateUpdate, Vertex, VertexId};
+use crate::runtime::ToolRuntime;
+use crate::workflow::node::ToolNodeConfig;
+use crate::workflow::vertices::json_repair::parse_lenient;
+use crate::workflow::vertices::tool::build_tool_arguments;
+
+/// A vertex that executes several independent tools concurrently within a
+/// single superstep, one `WorkflowMessage::Data` emitted per tool keyed by
+/// its `result_path`.
+pub struct ParallelToolVertex<S: WorkflowState> {
+    id: VertexId,
+    tools: Vec<(ToolNodeConfig, Arc<DynTool>)>,
+    runtime: Arc<ToolRuntime>,
+    /// Maximum number of tools executing concurrently
+    max_in_flight: usize,
+    /// If true, the first tool failure aborts the whole vertex; otherwise
+    /// failures are recorded per-tool in the output instead.
+    fail_fast: bool,
+    _phantom: std::marker::PhantomData<S>,
+}
+
+impl<S: WorkflowState> ParallelToolVertex<S> {
+    /// Create a new `ParallelToolVertex`
+    pub fn new(
+        id: impl Into<VertexId>,
+        tools: Vec<(ToolNodeConfig, Arc<DynTool>)>,
+        runtime: Arc<ToolRuntime>,
+        max_in_flight: usize,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            tools,
+            runtime,
+            max_in_flight: max_in_flight.max(1),
+            fail_fast: false,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Abort the whole vertex on the first tool failure instead of
+    /// recording it as a structured per-tool error
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    fn result_key(config: &ToolNodeConfig) -> String {
+        config
+            .result_path
+            .clone()
+            .unwrap_or_else(|| format!("{}_result", config.tool_name))
+    }
+}
+
+#[async_trait]
+impl<S: WorkflowState> Vertex<S, WorkflowMessage> for ParallelToolVertex<S> {
+    fn id(&self) -> &VertexId {
+        &self.id
+    }
+
+    async fn compute(
+        &self,
+        ctx: &mut ComputeContext<'_, S, WorkflowMessage>,
+    ) -> Result<ComputeResult<S::Update>, PregelError> {
+        let semaphore = Arc::new(Semaphore::new(self.max_in_flight));
+        let mut in_flight = FuturesUnordered::new();
+
+        for (config, tool) in &self.tools {
+            let args = build_tool_arguments(&self.id, config, ctx.state);
+            let semaphore = Arc::clone(&semaphore);
+            let tool = Arc::clone(tool);
+            let runtime = Arc::clone(&self.runtime);
+            let key = Self::result_key(config);
+            let tool_name = config.tool_name.clone();
+
+            in_flight.push(async move {
+                let _permit = semaphore.acquire_owned().await.expect("semaphore is never closed");
+                let result = tool.execute(args, &runtime).await;
+                (key, tool_name, result)
+            });
+        }
+
+        let mut outputs = Vec::with_capacity(self.tools.len());
+        while let Some((key, tool_name, result)) = in_flight.next().await {
+            match result {
+                Ok(result_str) => {
+                    outputs.push((key, parse_lenient(&result_str)));
+                }
+                Err(e) if self.fail_fast => {
+                    return Err(PregelError::vertex_error(
+                        self.id.clone(),
+                        format!("tool '{tool_name}' failed: {e}"),
+                    ));
+                }
+                Err(e) => {
+                    outputs.push((
+                        key,
+                        serde_json::json!({"error": e.to_string(), "tool": tool_name}),
+                    ));
+                }
+            }
+        }
+
+        // Deterministic output order regardless of completion order.
+        outputs.sort_by(|a, b| a.0.cmp(&b.0));
+        for (key, value) in outputs {
+            ctx.send_message("output", WorkflowMessage::Data { key, value });
+        }
+
+        Ok(ComputeResult::halt(S::Update::empty()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backends::MemoryBackend;
+    use crate::error::MiddlewareError;
+    use crate::middleware::ToolDefinition;
+    use crate::pregel::state::UnitState;
+    use crate::state::AgentState;
+
+    struct MockTool {
+        name: String,
+        response: Result<String, String>,
+    }
+
+    #[async_trait]
+    impl crate::middleware::Tool for MockTool {
+        fn definition(&self) -> ToolDefinition {
+            ToolDefinition {
+                name: self.name.clone(),
+                description: "Mock tool for testing".to_string(),
+                parameters: serde_json::json!({"type": "object", "properties": {}}),
+            }
+        }
+
+        async fn execute(
+            &self,
+            _args: serde_json::Value,
+            _runtime: &ToolRuntime,
+        ) -> Result<String, MiddlewareError> {
+            self.response
+                .clone()
+                .map_err(MiddlewareError::ToolExecution)
+        }
+    }
+
+    fn test_runtime() -> Arc<ToolRuntime> {
+        Arc::new(ToolRuntime::new(AgentState::new(), Arc::new(MemoryBackend::new())))
+    }
+
+    #[tokio::test]
+    async fn fans_out_independent_tools_and_emits_one_message_each() {
+        let tools: Vec<(ToolNodeConfig, Arc<DynTool>)> = vec![
+            (
+                ToolNodeConfig {
+                    tool_name: "search_a".to_string(),
+                    result_path: Some("a_result".to_string()),
+                    ..Default::default()
+                },
+                Arc::new(MockTool {
+                    name: "search_a".to_string(),
+                    response: Ok(r#"{"hits": 1}"#.to_string()),
+                }),
+            ),
+            (
+                ToolNodeConfig {
+                    tool_name: "search_b".to_string(),
+                    result_path: Some("b_result".to_string()),
+                    ..Default::default()
+                },
+                Arc::new(MockTool {
+                    name: "search_b".to_string(),
+                    response: Ok(r#"{"hits": 2}"#.to_string()),
+                }),
+            ),
+        ];
+
+        let vertex: ParallelToolVertex<UnitState> =
+            ParallelToolVertex::new("fanout", tools, test_runtime(), 2);
+
+        let mut ctx =
+            ComputeContext::<UnitState, WorkflowMessage>::new("fanout".into(), &[], 0, &UnitState);
+
+        let result = vertex.compute(&mut ctx).await.unwrap();
+        assert!(result.state.is_halted());
+
+        let outbox = ctx.into_outbox();
+        let messages = outbox.get(&VertexId::new("output")).unwrap();
+        assert_eq!(messages.len(), 2);
+
+        let keys: Vec<&str> = messages
+            .iter()
+            .map(|m| match m {
+                WorkflowMessage::Data { key, .. } => key.as_str(),
+                _ => panic!("Expected Data message"),
+            })
+            .collect();
+        assert_eq!(keys, vec!["a_result", "b_result"]);
+    }
+
+    #[tokio::test]
+    async fn records_per_tool_failure_without_fail_fast() {
+        let tools: Vec<(ToolNodeConfig, Arc<DynTool>)> = vec![(
+            ToolNodeConfig {
+                tool_name: "flaky".to_string(),
+                result_path: Some("flaky_result".to_string()),
+                ..Default::default()
+            },
+            Arc::new(MockTool {
+                name: "flaky".to_string(),
+                response: Err("boom".to_string()),
+            }),
+        )];
+
+        let vertex: ParallelToolVertex<UnitState> =
+            ParallelToolVertex::new("fanout", tools, test_runtime(), 2);
+
+        let mut ctx =
+            ComputeContext::<UnitState, WorkflowMessage>::new("fanout".into(), &[], 0, &UnitState);
+
+        let result = vertex.compute(&mut ctx).await.unwrap();
+        assert!(result.state.is_halted());
+
+        let outbox = ctx.into_outbox();
+        let messages = outbox.get(&VertexId::new("output")).unwrap();
+        match &messages[0] {
+            WorkflowMessage::Data { key, value } => {
+                assert_eq!(key, "flaky_result");
+                assert_eq!(value.get("tool"), Some(&serde_json::json!("flaky")));
+            }
+            _ => panic!("Expected Data message"),
+        }
+    }
+
+    #[tokio::test]
+    async fn fail_fast_aborts_on_first_error() {
+        let tools: Vec<(ToolNodeConfig, Arc<DynTool>)> = vec![(
+            ToolNodeConfig {
+                tool_name: "flaky".to_string(),
+                ..Default::default()
+            },
+            Arc::new(MockTool {
+                name: "flaky".to_string(),
+                response: Err("boom".to_string()),
+            }),
+        )];
+
+        let vertex: ParallelToolVertex<UnitState> =
+            ParallelToolVertex::new("fanout", tools, test_runtime(), 2).with_fail_fast(true);
+
+        let mut ctx =
+            ComputeContext::<UnitState, WorkflowMessage>::new("fanout".into(), &[], 0, &UnitState);
+
+        assert!(vertex.compute(&mut ctx).await.is_err());
+    }
+}
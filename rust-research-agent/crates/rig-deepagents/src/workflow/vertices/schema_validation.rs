@@ -0,0 +1,152 @@
+//! Validate assembled tool arguments against a tool's parameter JSON Schema
+//! before execution, so a bad `static_args`/`state_arg_paths` merge fails
+//! with a precise, vertex-level message instead of deep inside the tool.
+//!
+//! Only the subset of JSON Schema that `ToolDefinition::parameters` actually
+//! uses is enforced: `type: object`, `required`, per-property `type`, and
+//! `additionalProperties: false`. Anything more exotic (e.g. `oneOf`,
+//! `pattern`) is left to the tool itself.
+
+use serde_json::Value;
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Returns `Err(message)` naming the first offending field if `args` doesn't
+/// satisfy `schema`. Under a schema with no `type`/`properties`/`required` at
+/// all (the common case for loosely-specified tools) any object of arguments
+/// passes; non-object `args` are always rejected.
+pub(crate) fn validate_tool_arguments(tool_name: &str, schema: &Value, args: &Value) -> Result<(), String> {
+    let declared_type = schema.get("type").and_then(Value::as_str);
+    if matches!(declared_type, Some(t) if t != "object") {
+        return Err(format!(
+            "tool '{tool_name}' declares a non-object parameter schema ('{}'), which is unsupported for argument validation",
+            declared_type.unwrap()
+        ));
+    }
+
+    let args_obj = args.as_object().ok_or_else(|| {
+        format!("tool '{tool_name}' expects an object of arguments, got {}", json_type_name(args))
+    })?;
+
+    if let Some(required) = schema.get("required").and_then(Value::as_array) {
+        for field in required {
+            let Some(field_name) = field.as_str() else { continue };
+            if !args_obj.contains_key(field_name) {
+                return Err(format!(
+                    "tool '{tool_name}' is missing required argument '{field_name}'"
+                ));
+            }
+        }
+    }
+
+    let properties = schema.get("properties").and_then(Value::as_object);
+
+    if let Some(properties) = properties {
+        for (field_name, field_schema) in properties {
+            let Some(expected_type) = field_schema.get("type").and_then(Value::as_str) else {
+                continue;
+            };
+            let Some(value) = args_obj.get(field_name) else {
+                continue;
+            };
+            let actual_type = json_type_name(value);
+            let matches = actual_type == expected_type
+                || (expected_type == "number" && actual_type == "integer");
+            if !matches {
+                return Err(format!(
+                    "tool '{tool_name}' argument '{field_name}' should be of type '{expected_type}', got '{actual_type}'"
+                ));
+            }
+        }
+    }
+
+    let additional_properties_allowed = schema
+        .get("additionalProperties")
+        .and_then(Value::as_bool)
+        .unwrap_or(true);
+
+    if !additional_properties_allowed {
+        let known_fields = properties.map(|p| p.keys().collect::<Vec<_>>()).unwrap_or_default();
+        for field_name in args_obj.keys() {
+            if !known_fields.iter().any(|k| *k == field_name) {
+                return Err(format!(
+                    "tool '{tool_name}' does not accept unknown argument '{field_name}'"
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn passes_when_schema_has_no_constraints() {
+        let schema = json!({"type": "object", "properties": {}});
+        assert!(validate_tool_arguments("tool", &schema, &json!({"anything": 1})).is_ok());
+    }
+
+    #[test]
+    fn rejects_missing_required_property() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"query": {"type": "string"}},
+            "required": ["query"]
+        });
+        let err = validate_tool_arguments("search", &schema, &json!({})).unwrap_err();
+        assert!(err.contains("query"), "error should name the missing field: {err}");
+    }
+
+    #[test]
+    fn rejects_wrong_property_type() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"limit": {"type": "integer"}}
+        });
+        let err = validate_tool_arguments("search", &schema, &json!({"limit": "ten"})).unwrap_err();
+        assert!(err.contains("limit"), "error should name the offending field: {err}");
+    }
+
+    #[test]
+    fn accepts_integer_for_number_property() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"score": {"type": "number"}}
+        });
+        assert!(validate_tool_arguments("rank", &schema, &json!({"score": 3})).is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_keys_when_additional_properties_false() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"query": {"type": "string"}},
+            "additionalProperties": false
+        });
+        let err = validate_tool_arguments("search", &schema, &json!({"query": "x", "extra": 1})).unwrap_err();
+        assert!(err.contains("extra"), "error should name the unknown field: {err}");
+    }
+
+    #[test]
+    fn allows_unknown_keys_by_default() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"query": {"type": "string"}}
+        });
+        assert!(validate_tool_arguments("search", &schema, &json!({"query": "x", "extra": 1})).is_ok());
+    }
+}
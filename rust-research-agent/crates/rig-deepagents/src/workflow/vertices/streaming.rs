@@ -0,0 +1,133 @@
+//! Types for assembling a complete assistant turn out of streamed deltas
+//!
+//! `LLMProvider::complete_stream` yields a sequence of `StreamDelta`s as the
+//! provider generates a response. Content arrives incrementally; tool calls
+//! arrive fragmented across multiple deltas keyed by their position in the
+//! assistant's tool-call list. `AgentVertex` folds these into a
+//! [`PartialToolCall`] per index and only finalizes a `ToolCall` once the
+//! provider reports the turn is done.
+
+use std::collections::HashMap;
+
+use crate::state::ToolCall;
+
+/// One fragment of a streamed assistant turn
+#[derive(Debug, Clone, Default)]
+pub struct StreamDelta {
+    /// A fragment of assistant text content, if any arrived in this delta
+    pub content: Option<String>,
+    /// Partial tool-call data, if any arrived in this delta
+    pub tool_call: Option<ToolCallDelta>,
+    /// Set once the provider signals this is the last delta of the turn
+    pub finished: bool,
+}
+
+/// A fragment of one tool call, identified by its index in the assistant's
+/// tool-call list (providers stream multiple tool calls interleaved by index)
+#[derive(Debug, Clone)]
+pub struct ToolCallDelta {
+    /// Index of the tool call this fragment belongs to
+    pub index: usize,
+    /// The tool-call id, present on the first fragment for this index
+    pub id: Option<String>,
+    /// The tool name, present on the first fragment for this index
+    pub name: Option<String>,
+    /// A fragment of the JSON-encoded arguments string to append
+    pub arguments_fragment: Option<String>,
+}
+
+/// Accumulator for one tool call's fragments as they stream in
+#[derive(Debug, Clone, Default)]
+pub struct PartialToolCall {
+    pub id: String,
+    pub name: String,
+    pub arguments_json: String,
+}
+
+impl PartialToolCall {
+    /// Fold one fragment into this accumulator
+    fn apply(&mut self, delta: &ToolCallDelta) {
+        if let Some(id) = &delta.id {
+            self.id = id.clone();
+        }
+        if let Some(name) = &delta.name {
+            self.name = name.clone();
+        }
+        if let Some(fragment) = &delta.arguments_fragment {
+            self.arguments_json.push_str(fragment);
+        }
+    }
+
+    /// Parse the accumulated arguments string into a finalized `ToolCall`
+    ///
+    /// An empty arguments string (no deltas carried any) finalizes to `{}`
+    /// rather than failing, since some providers omit arguments entirely for
+    /// no-arg tools.
+    fn finalize(self) -> Result<ToolCall, serde_json::Error> {
+        let arguments = if self.arguments_json.trim().is_empty() {
+            serde_json::json!({})
+        } else {
+            serde_json::from_str(&self.arguments_json)?
+        };
+        Ok(ToolCall {
+            id: self.id,
+            name: self.name,
+            arguments,
+        })
+    }
+}
+
+/// Folds a sequence of `StreamDelta`s into accumulated content and tool calls
+#[derive(Debug, Default)]
+pub struct StreamAssembler {
+    content: String,
+    tool_calls: HashMap<usize, PartialToolCall>,
+    finished: bool,
+}
+
+impl StreamAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold one delta into the running accumulation
+    pub fn push(&mut self, delta: &StreamDelta) {
+        if let Some(fragment) = &delta.content {
+            self.content.push_str(fragment);
+        }
+        if let Some(tool_delta) = &delta.tool_call {
+            self.tool_calls
+                .entry(tool_delta.index)
+                .or_default()
+                .apply(tool_delta);
+        }
+        if delta.finished {
+            self.finished = true;
+        }
+    }
+
+    /// The content accumulated so far, for emitting intermediate progress
+    pub fn content_so_far(&self) -> &str {
+        &self.content
+    }
+
+    /// Finalize into a complete content string and ordered tool-call list.
+    /// Fails if no pushed delta had `finished` set.
+    pub fn finish(self) -> Result<(String, Vec<ToolCall>), serde_json::Error> {
+        if !self.finished {
+            return Err(<serde_json::Error as serde::de::Error>::custom(
+                "stream ended before the provider signalled the end of the turn",
+            ));
+        }
+
+        let mut indices: Vec<usize> = self.tool_calls.keys().copied().collect();
+        indices.sort_unstable();
+
+        let mut tool_calls = Vec::with_capacity(indices.len());
+        for index in indices {
+            tool_calls.push(self.tool_calls[&index].clone().finalize()?);
+        }
+
+        Ok((self.content, tool_calls))
+    }
+}
@@ -5,14 +5,121 @@
 
 use async_trait::async_trait;
 use std::sync::Arc;
+use std::time::Duration;
 
+use crate::error::MiddlewareError;
 use crate::middleware::DynTool;
+use crate::pregel::config::RetryPolicy;
 use crate::pregel::error::PregelError;
 use crate::pregel::message::WorkflowMessage;
 use crate::pregel::state::WorkflowState;
 use crate::pregel::vertex::{ComputeContext, ComputeResult, StateUpdate, Vertex, VertexId};
 use crate::runtime::ToolRuntime;
 use crate::workflow::node::ToolNodeConfig;
+use crate::workflow::vertices::schema_validation::validate_tool_arguments;
+
+/// Whether a tool execution failure is worth retrying. Timeouts and errors
+/// whose message reads as transient (network/availability issues) are
+/// retriable; anything else (bad arguments, tool-reported logic errors) is
+/// treated as fatal so retries don't mask a bug as flakiness.
+fn is_retriable(error: &MiddlewareError) -> bool {
+    let message = error.to_string().to_lowercase();
+    message.contains("timed out")
+        || message.contains("timeout")
+        || message.contains("connection")
+        || message.contains("unavailable")
+        || message.contains("429")
+        || message.contains("rate limit")
+}
+
+/// Execute `tool` with retry-with-backoff and a per-attempt timeout,
+/// returning the successful result along with the number of attempts made.
+/// Shared by [`ToolVertex`]; the single-attempt path (no `retry_policy`
+/// configured) is just `max_attempts == 1`, so this is the only execution
+/// path `ToolVertex::compute` needs.
+async fn execute_with_retry(
+    vertex_id: &VertexId,
+    tool_name: &str,
+    tool: &DynTool,
+    args: serde_json::Value,
+    runtime: &ToolRuntime,
+    retry_policy: Option<&RetryPolicy>,
+    per_attempt_timeout: Option<Duration>,
+    superstep: usize,
+) -> Result<(String, u32), PregelError> {
+    let max_attempts = retry_policy.map(|p| p.max_attempts).unwrap_or(1).max(1);
+
+    for attempt in 1..=max_attempts {
+        let call = tool.execute(args.clone(), runtime);
+        let outcome = match per_attempt_timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, call).await {
+                Ok(result) => result,
+                Err(_) => Err(MiddlewareError::ToolExecution(format!(
+                    "tool '{tool_name}' timed out after {timeout:?}"
+                ))),
+            },
+            None => call.await,
+        };
+
+        match outcome {
+            Ok(result) => return Ok((result, attempt)),
+            Err(e) => {
+                let retriable = is_retriable(&e);
+                let has_more_attempts = attempt < max_attempts;
+                if retriable && has_more_attempts {
+                    let policy = retry_policy.expect("retry_policy is Some whenever max_attempts > 1");
+                    tracing::warn!(
+                        vertex_id = %vertex_id,
+                        tool_name = %tool_name,
+                        attempt,
+                        max_attempts,
+                        superstep,
+                        error = %e,
+                        "tool execution failed, retrying"
+                    );
+                    tokio::time::sleep(policy.delay_for_attempt(attempt - 1)).await;
+                    continue;
+                }
+                return Err(PregelError::vertex_error(
+                    vertex_id.clone(),
+                    format!("Tool execution failed after {attempt} attempt(s): {e}"),
+                ));
+            }
+        }
+    }
+
+    unreachable!("loop always returns on its last iteration")
+}
+
+/// Build arguments by merging a tool's static config args with arguments
+/// resolved from workflow state (`state_arg_paths` wins on key collisions,
+/// so an upstream vertex's output can override a config-time default).
+/// Shared by [`ToolVertex`] and `ParallelToolVertex`.
+pub(crate) fn build_tool_arguments(
+    id: &VertexId,
+    config: &ToolNodeConfig,
+    state: &impl WorkflowState,
+) -> serde_json::Value {
+    let mut args = config.static_args.clone();
+
+    for (arg_name, state_path) in &config.state_arg_paths {
+        match state.get_path(state_path) {
+            Some(value) => {
+                args.insert(arg_name.clone(), value);
+            }
+            None => {
+                tracing::debug!(
+                    vertex_id = %id,
+                    arg_name = %arg_name,
+                    state_path = %state_path,
+                    "state arg path did not resolve to a value"
+                );
+            }
+        }
+    }
+
+    serde_json::Value::Object(args.into_iter().collect())
+}
 
 /// A vertex that executes a single tool
 ///
@@ -63,27 +170,8 @@ impl<S: WorkflowState> ToolVertex<S> {
     }
 
     /// Build arguments by merging static args with state-resolved args
-    fn build_arguments(&self, _state: &S) -> serde_json::Value {
-        let args = self.config.static_args.clone();
-
-        // TODO: Resolve state_arg_paths from workflow state
-        // For now, we only use static args
-        // In a full implementation, we would:
-        // 1. Parse each state_arg_path (e.g., "research.query")
-        // 2. Extract the value from the workflow state
-        // 3. Merge it into args
-
-        for (arg_name, _state_path) in &self.config.state_arg_paths {
-            // Placeholder: in a real implementation, resolve state_path from state
-            // For now, skip dynamic args
-            tracing::debug!(
-                vertex_id = %self.id,
-                arg_name = %arg_name,
-                "Skipping state arg (not yet implemented)"
-            );
-        }
-
-        serde_json::Value::Object(args.into_iter().collect())
+    fn build_arguments(&self, state: &S) -> serde_json::Value {
+        build_tool_arguments(&self.id, &self.config, state)
     }
 }
 
@@ -107,22 +195,47 @@ impl<S: WorkflowState> Vertex<S, WorkflowMessage> for ToolVertex<S> {
         // Build arguments from config and state
         let args = self.build_arguments(ctx.state);
 
-        // Execute the tool
-        let result_str = self
-            .tool
-            .execute(args, &self.runtime)
-            .await
-            .map_err(|e| PregelError::vertex_error(self.id.clone(), format!("Tool execution failed: {}", e)))?;
+        // Validate against the tool's own parameter schema before ever
+        // calling it, so a bad static_args/state_arg_paths merge fails
+        // here with a precise message instead of deep inside the tool.
+        if let Err(message) = validate_tool_arguments(&self.config.tool_name, &self.tool.definition().parameters, &args) {
+            if self.config.lenient_validation {
+                tracing::warn!(
+                    vertex_id = %self.id,
+                    tool_name = %self.config.tool_name,
+                    %message,
+                    "tool argument validation failed; proceeding because lenient_validation is set"
+                );
+            } else {
+                return Err(PregelError::vertex_error(self.id.clone(), message));
+            }
+        }
+
+        // Execute the tool, retrying on transient failures per
+        // `config.retry_policy` and bounding each attempt by
+        // `config.tool_timeout` when set.
+        let (result_str, attempts) = execute_with_retry(
+            &self.id,
+            &self.config.tool_name,
+            &self.tool,
+            args,
+            &self.runtime,
+            self.config.retry_policy.as_ref(),
+            self.config.tool_timeout,
+            ctx.superstep,
+        )
+        .await?;
 
         tracing::info!(
             vertex_id = %self.id,
             tool_name = %self.config.tool_name,
+            attempts,
             "Tool execution completed"
         );
 
-        // Try to parse result as JSON, fallback to string
-        let result_value = serde_json::from_str(&result_str)
-            .unwrap_or_else(|_| serde_json::Value::String(result_str));
+        // Try to parse result as JSON; repair common truncation before
+        // giving up and falling back to an opaque string
+        let result_value = crate::workflow::vertices::json_repair::parse_lenient(&result_str);
 
         // Build output key based on result_path or default
         let output_key = self
@@ -135,11 +248,24 @@ impl<S: WorkflowState> Vertex<S, WorkflowMessage> for ToolVertex<S> {
         ctx.send_message(
             "output",
             WorkflowMessage::Data {
-                key: output_key,
+                key: output_key.clone(),
                 value: result_value,
             },
         );
 
+        // Surface the attempt count alongside the result whenever a retry
+        // policy is configured, so downstream vertices can observe flakiness
+        // even on eventual success.
+        if self.config.retry_policy.is_some() {
+            ctx.send_message(
+                "output",
+                WorkflowMessage::Data {
+                    key: format!("{output_key}__attempts"),
+                    value: serde_json::json!(attempts),
+                },
+            );
+        }
+
         // Tool vertices complete after single execution
         Ok(ComputeResult::halt(S::Update::empty()))
     }
@@ -192,6 +318,32 @@ mod tests {
         }
     }
 
+    // A tool that returns a raw, possibly-malformed string verbatim, to
+    // exercise the JSON repair path (MockTool always serializes valid JSON)
+    struct MockRawTool {
+        name: String,
+        raw_response: String,
+    }
+
+    #[async_trait]
+    impl crate::middleware::Tool for MockRawTool {
+        fn definition(&self) -> ToolDefinition {
+            ToolDefinition {
+                name: self.name.clone(),
+                description: "Mock tool returning a raw string".to_string(),
+                parameters: serde_json::json!({"type": "object", "properties": {}}),
+            }
+        }
+
+        async fn execute(
+            &self,
+            _args: serde_json::Value,
+            _runtime: &ToolRuntime,
+        ) -> Result<String, MiddlewareError> {
+            Ok(self.raw_response.clone())
+        }
+    }
+
     fn create_test_runtime() -> Arc<ToolRuntime> {
         let backend = Arc::new(MemoryBackend::new());
         Arc::new(ToolRuntime::new(AgentState::new(), backend))
@@ -286,6 +438,46 @@ mod tests {
         assert_eq!(obj.get("key2"), Some(&serde_json::json!(42)));
     }
 
+    #[test]
+    fn test_tool_vertex_build_arguments_resolves_state_arg_paths() {
+        use crate::pregel::state::MapState;
+        use std::collections::HashMap as StdHashMap;
+
+        let mock_tool: Arc<DynTool> = Arc::new(MockTool::new("search", serde_json::json!({})));
+        let runtime = create_test_runtime();
+
+        let mut static_args = HashMap::new();
+        static_args.insert("limit".to_string(), serde_json::json!(10));
+        // Overridden by the state-resolved value below.
+        static_args.insert("query".to_string(), serde_json::json!("default query"));
+
+        let mut state_arg_paths = StdHashMap::new();
+        state_arg_paths.insert("query".to_string(), "research.query".to_string());
+        state_arg_paths.insert("missing".to_string(), "research.not_there".to_string());
+
+        let config = ToolNodeConfig {
+            tool_name: "search".to_string(),
+            static_args,
+            state_arg_paths,
+            ..Default::default()
+        };
+
+        let vertex: ToolVertex<MapState> = ToolVertex::new("search_node", config, mock_tool, runtime);
+
+        let mut state = MapState::new();
+        state.apply(crate::pregel::state::MapUpdate::set(
+            "research",
+            serde_json::json!({"query": "rust async runtimes"}),
+        ));
+
+        let args = vertex.build_arguments(&state);
+        let obj = args.as_object().unwrap();
+
+        assert_eq!(obj.get("query"), Some(&serde_json::json!("rust async runtimes")));
+        assert_eq!(obj.get("limit"), Some(&serde_json::json!(10)));
+        assert!(obj.get("missing").is_none());
+    }
+
     #[tokio::test]
     async fn test_tool_vertex_default_result_path() {
         let mock_tool: Arc<DynTool> = Arc::new(MockTool::new("my_tool", serde_json::json!("done")));
@@ -319,4 +511,296 @@ mod tests {
             _ => panic!("Expected Data message"),
         }
     }
+
+    // A tool whose schema requires a "query" string and forbids unknown
+    // arguments, to exercise the pre-execution validation pass.
+    struct StrictTool {
+        response: String,
+    }
+
+    #[async_trait]
+    impl crate::middleware::Tool for StrictTool {
+        fn definition(&self) -> ToolDefinition {
+            ToolDefinition {
+                name: "strict".to_string(),
+                description: "Mock tool with a strict parameter schema".to_string(),
+                parameters: serde_json::json!({
+                    "type": "object",
+                    "properties": {"query": {"type": "string"}},
+                    "required": ["query"],
+                    "additionalProperties": false
+                }),
+            }
+        }
+
+        async fn execute(
+            &self,
+            _args: serde_json::Value,
+            _runtime: &ToolRuntime,
+        ) -> Result<String, MiddlewareError> {
+            Ok(self.response.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tool_vertex_rejects_arguments_that_fail_schema_validation() {
+        let mock_tool: Arc<DynTool> = Arc::new(StrictTool {
+            response: serde_json::json!({"ok": true}).to_string(),
+        });
+        let runtime = create_test_runtime();
+
+        // Missing the required "query" argument entirely.
+        let config = ToolNodeConfig {
+            tool_name: "strict".to_string(),
+            ..Default::default()
+        };
+
+        let vertex: ToolVertex<UnitState> = ToolVertex::new("strict_node", config, mock_tool, runtime);
+
+        let mut ctx = ComputeContext::<UnitState, WorkflowMessage>::new(
+            "strict_node".into(),
+            &[],
+            0,
+            &UnitState,
+        );
+
+        let err = vertex.compute(&mut ctx).await.unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("query"), "error should name the missing field: {message}");
+    }
+
+    #[tokio::test]
+    async fn test_tool_vertex_downgrades_validation_failure_to_warning_when_lenient() {
+        let mock_tool: Arc<DynTool> = Arc::new(StrictTool {
+            response: serde_json::json!({"ok": true}).to_string(),
+        });
+        let runtime = create_test_runtime();
+
+        let config = ToolNodeConfig {
+            tool_name: "strict".to_string(),
+            lenient_validation: true,
+            ..Default::default()
+        };
+
+        let vertex: ToolVertex<UnitState> = ToolVertex::new("strict_node", config, mock_tool, runtime);
+
+        let mut ctx = ComputeContext::<UnitState, WorkflowMessage>::new(
+            "strict_node".into(),
+            &[],
+            0,
+            &UnitState,
+        );
+
+        // Still missing "query", but lenient_validation downgrades this to
+        // a warning and the tool executes anyway.
+        let result = vertex.compute(&mut ctx).await.unwrap();
+        assert!(result.state.is_halted());
+    }
+
+    // A tool that fails with a retriable error for its first
+    // `remaining_failures` calls (an atomic counter), then succeeds.
+    struct FlakyTool {
+        remaining_failures: std::sync::atomic::AtomicU32,
+        response: String,
+    }
+
+    #[async_trait]
+    impl crate::middleware::Tool for FlakyTool {
+        fn definition(&self) -> ToolDefinition {
+            ToolDefinition {
+                name: "flaky".to_string(),
+                description: "Mock tool that fails transiently".to_string(),
+                parameters: serde_json::json!({"type": "object", "properties": {}}),
+            }
+        }
+
+        async fn execute(
+            &self,
+            _args: serde_json::Value,
+            _runtime: &ToolRuntime,
+        ) -> Result<String, MiddlewareError> {
+            use std::sync::atomic::Ordering;
+            if self.remaining_failures.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                if n > 0 { Some(n - 1) } else { None }
+            }).is_ok() {
+                return Err(MiddlewareError::ToolExecution("connection reset by peer".to_string()));
+            }
+            Ok(self.response.clone())
+        }
+    }
+
+    // A tool whose failures are never retriable (e.g. a bad-request style error).
+    struct AlwaysFailingTool;
+
+    #[async_trait]
+    impl crate::middleware::Tool for AlwaysFailingTool {
+        fn definition(&self) -> ToolDefinition {
+            ToolDefinition {
+                name: "broken".to_string(),
+                description: "Mock tool that always fails fatally".to_string(),
+                parameters: serde_json::json!({"type": "object", "properties": {}}),
+            }
+        }
+
+        async fn execute(
+            &self,
+            _args: serde_json::Value,
+            _runtime: &ToolRuntime,
+        ) -> Result<String, MiddlewareError> {
+            Err(MiddlewareError::ToolExecution("malformed request".to_string()))
+        }
+    }
+
+    // A tool that never returns, to exercise the per-attempt timeout.
+    struct HangingTool;
+
+    #[async_trait]
+    impl crate::middleware::Tool for HangingTool {
+        fn definition(&self) -> ToolDefinition {
+            ToolDefinition {
+                name: "hanging".to_string(),
+                description: "Mock tool that never completes".to_string(),
+                parameters: serde_json::json!({"type": "object", "properties": {}}),
+            }
+        }
+
+        async fn execute(
+            &self,
+            _args: serde_json::Value,
+            _runtime: &ToolRuntime,
+        ) -> Result<String, MiddlewareError> {
+            std::future::pending().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tool_vertex_retries_transient_failures_and_records_attempts() {
+        let mock_tool: Arc<DynTool> = Arc::new(FlakyTool {
+            remaining_failures: std::sync::atomic::AtomicU32::new(2),
+            response: serde_json::json!({"status": "ok"}).to_string(),
+        });
+        let runtime = create_test_runtime();
+
+        let config = ToolNodeConfig {
+            tool_name: "flaky".to_string(),
+            result_path: Some("flaky_result".to_string()),
+            retry_policy: Some(RetryPolicy {
+                max_attempts: 3,
+                initial_delay: std::time::Duration::from_millis(1),
+                multiplier: 1.0,
+                max_delay: std::time::Duration::from_millis(5),
+                jitter: false,
+            }),
+            ..Default::default()
+        };
+
+        let vertex: ToolVertex<UnitState> = ToolVertex::new("flaky_node", config, mock_tool, runtime);
+
+        let mut ctx = ComputeContext::<UnitState, WorkflowMessage>::new(
+            "flaky_node".into(),
+            &[],
+            0,
+            &UnitState,
+        );
+
+        let result = vertex.compute(&mut ctx).await.unwrap();
+        assert!(result.state.is_halted());
+
+        let outbox = ctx.into_outbox();
+        let messages = outbox.get(&VertexId::new("output")).unwrap();
+        assert_eq!(messages.len(), 2);
+
+        match &messages[1] {
+            WorkflowMessage::Data { key, value } => {
+                assert_eq!(key, "flaky_result__attempts");
+                assert_eq!(value, &serde_json::json!(3));
+            }
+            _ => panic!("Expected Data message"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tool_vertex_does_not_retry_fatal_errors() {
+        let mock_tool: Arc<DynTool> = Arc::new(AlwaysFailingTool);
+        let runtime = create_test_runtime();
+
+        let config = ToolNodeConfig {
+            tool_name: "broken".to_string(),
+            retry_policy: Some(RetryPolicy::default()),
+            ..Default::default()
+        };
+
+        let vertex: ToolVertex<UnitState> = ToolVertex::new("broken_node", config, mock_tool, runtime);
+
+        let mut ctx = ComputeContext::<UnitState, WorkflowMessage>::new(
+            "broken_node".into(),
+            &[],
+            0,
+            &UnitState,
+        );
+
+        let err = vertex.compute(&mut ctx).await.unwrap_err();
+        assert!(err.to_string().contains("1 attempt"), "fatal errors should not be retried: {err}");
+    }
+
+    #[tokio::test]
+    async fn test_tool_vertex_times_out_a_hanging_tool() {
+        let mock_tool: Arc<DynTool> = Arc::new(HangingTool);
+        let runtime = create_test_runtime();
+
+        let config = ToolNodeConfig {
+            tool_name: "hanging".to_string(),
+            tool_timeout: Some(std::time::Duration::from_millis(10)),
+            ..Default::default()
+        };
+
+        let vertex: ToolVertex<UnitState> = ToolVertex::new("hanging_node", config, mock_tool, runtime);
+
+        let mut ctx = ComputeContext::<UnitState, WorkflowMessage>::new(
+            "hanging_node".into(),
+            &[],
+            0,
+            &UnitState,
+        );
+
+        let err = vertex.compute(&mut ctx).await.unwrap_err();
+        assert!(err.to_string().contains("timed out"), "expected a timeout error: {err}");
+    }
+
+    #[tokio::test]
+    async fn test_tool_vertex_repairs_truncated_json_result() {
+        let mock_tool: Arc<DynTool> = Arc::new(MockRawTool {
+            name: "flaky".to_string(),
+            raw_response: r#"{"results": ["a", "b""#.to_string(),
+        });
+        let runtime = create_test_runtime();
+
+        let config = ToolNodeConfig {
+            tool_name: "flaky".to_string(),
+            result_path: Some("flaky_result".to_string()),
+            ..Default::default()
+        };
+
+        let vertex: ToolVertex<UnitState> = ToolVertex::new("flaky_node", config, mock_tool, runtime);
+
+        let mut ctx = ComputeContext::<UnitState, WorkflowMessage>::new(
+            "flaky_node".into(),
+            &[],
+            0,
+            &UnitState,
+        );
+
+        let _ = vertex.compute(&mut ctx).await.unwrap();
+
+        let outbox = ctx.into_outbox();
+        let messages = outbox.get(&VertexId::new("output")).unwrap();
+
+        match &messages[0] {
+            WorkflowMessage::Data { key, value } => {
+                assert_eq!(key, "flaky_result");
+                assert_eq!(value, &serde_json::json!({"results": ["a", "b"]}));
+            }
+            _ => panic!("Expected Data message"),
+        }
+    }
 }
@@ -0,0 +1,73 @@
+//! Pluggable tool execution for `AgentVertex`
+//!
+//! `ToolExecutor` is the narrow interface an agent loop needs to actually run
+//! a tool call: given a name and JSON arguments, produce a JSON result (or
+//! fail). `ToolRegistry` maps tool names to executors so `AgentVertex` can
+//! dispatch each `ToolCall` it gets back from the LLM without knowing how any
+//! particular tool works.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::error::DeepAgentError;
+
+/// Executes a single named tool
+#[async_trait]
+pub trait ToolExecutor: Send + Sync {
+    /// Run the tool with the given arguments, returning its JSON result
+    async fn execute(
+        &self,
+        name: &str,
+        args: &serde_json::Value,
+    ) -> Result<serde_json::Value, DeepAgentError>;
+}
+
+/// Maps tool names to the executor responsible for running them
+#[derive(Clone, Default)]
+pub struct ToolRegistry {
+    executors: HashMap<String, Arc<dyn ToolExecutor>>,
+}
+
+impl ToolRegistry {
+    /// An empty registry with no registered tools
+    pub fn new() -> Self {
+        Self {
+            executors: HashMap::new(),
+        }
+    }
+
+    /// Register an executor under `name`, overwriting any prior registration
+    pub fn register(&mut self, name: impl Into<String>, executor: Arc<dyn ToolExecutor>) {
+        self.executors.insert(name.into(), executor);
+    }
+
+    /// Builder-style variant of [`Self::register`]
+    pub fn with_tool(mut self, name: impl Into<String>, executor: Arc<dyn ToolExecutor>) -> Self {
+        self.register(name, executor);
+        self
+    }
+
+    /// Whether a tool with this name is registered
+    pub fn contains(&self, name: &str) -> bool {
+        self.executors.contains_key(name)
+    }
+
+    /// Dispatch a call to the named tool's executor
+    ///
+    /// Returns `DeepAgentError::AgentExecution` if no executor is registered
+    /// for `name`; callers are expected to have already applied
+    /// `allowed_tools` gating before reaching here.
+    pub async fn execute(
+        &self,
+        name: &str,
+        args: &serde_json::Value,
+    ) -> Result<serde_json::Value, DeepAgentError> {
+        match self.executors.get(name) {
+            Some(executor) => executor.execute(name, args).await,
+            None => Err(DeepAgentError::AgentExecution(format!(
+                "no executor registered for tool '{name}'"
+            ))),
+        }
+    }
+}